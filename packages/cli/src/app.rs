@@ -1,4 +1,9 @@
-use std::{fs, io, path::Path};
+use std::{
+    collections::HashSet,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
 
 use lingora_core::prelude::*;
 
@@ -9,12 +14,20 @@ use crate::{args::CliArgs, error::CliError};
 /// `App` owns:
 /// - the loaded configuration (`LingoraToml`)
 /// - the result of the full audit (`AuditResult`)
+/// - the outcome of reporting against a baseline, if any
 ///
 /// This struct acts as the bridge between parsed CLI arguments, core engine execution,
 /// and output/rendering logic.
 pub struct App {
     settings: LingoraToml,
     audit_result: AuditResult,
+    baseline: Option<BaselineSummary>,
+}
+
+/// The outcome of reporting an audit against a `Baseline`.
+struct BaselineSummary {
+    recorded: usize,
+    fixed: Vec<String>,
 }
 
 impl App {
@@ -31,6 +44,51 @@ impl App {
         Ok(())
     }
 
+    /// Renders the audit result as JSON using `JsonRenderer` to the given writer.
+    ///
+    /// The output follows the versioned schema documented in `docs/json_output.md`.
+    ///
+    /// # Errors
+    /// Returns `CliError::Lingora` if serializing or writing the output fails.
+    pub fn output_json_report<W: io::Write>(&self, out: &mut W) -> Result<(), CliError> {
+        let renderer = JsonRenderer::new(&self.audit_result);
+        renderer.render(out)?;
+        Ok(())
+    }
+
+    /// Renders the audit result as a SARIF 2.1.0 log using `SarifRenderer` to the given writer.
+    ///
+    /// Each issue kind is a rule, and each issue a result located at its source; issues
+    /// without a file of their own are located at `config_file`, if given.
+    ///
+    /// # Errors
+    /// Returns `CliError::Lingora` if serializing or writing the output fails.
+    pub fn output_sarif_report<W: io::Write>(
+        &self,
+        config_file: Option<&Path>,
+        out: &mut W,
+    ) -> Result<(), CliError> {
+        let renderer = SarifRenderer::new(&self.audit_result);
+        let renderer = match config_file {
+            Some(config_file) => renderer.with_config_file(config_file),
+            None => renderer,
+        };
+        renderer.render(out)?;
+        Ok(())
+    }
+
+    /// Renders the audit result as a JUnit XML report using `JunitRenderer` to the given writer.
+    ///
+    /// Each locale is a test suite, and each kind of issue a test case within it.
+    ///
+    /// # Errors
+    /// Returns `CliError::Lingora` if writing the output fails.
+    pub fn output_junit_report<W: io::Write>(&self, out: &mut W) -> Result<(), CliError> {
+        let renderer = JunitRenderer::new(&self.audit_result);
+        renderer.render(out)?;
+        Ok(())
+    }
+
     /// Generates `dioxus_i18n::I18nConfig` Rust code and writes it to the specified file.
     ///
     /// - Uses `DioxusI18nConfigRenderer` with the current `settings` and `workspace`
@@ -53,6 +111,155 @@ impl App {
         Ok(())
     }
 
+    /// Appends stub entries, generated by `FluentStubRenderer`, for the undefined identifier
+    /// literals in the Rust sources to the specified Fluent file, creating it if need be.
+    ///
+    /// The issues reported by the audit are not affected, so they still include the
+    /// undefined literals.
+    ///
+    /// # Errors
+    /// - `CliError::Io` on file read/write failure
+    /// - `CliError::Lingora` if rendering the stubs fails
+    pub fn extract_stubs(&self, path: &Path) -> Result<(), CliError> {
+        let mut stubs = Vec::new();
+        FluentStubRenderer::new(&self.audit_result).render(&mut stubs)?;
+
+        if stubs.is_empty() {
+            return Ok(());
+        }
+
+        let existing = fs::read_to_string(path).or_else(|error| match error.kind() {
+            io::ErrorKind::NotFound => Ok(String::new()),
+            _ => Err(error),
+        })?;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        if !existing.is_empty() && !existing.ends_with('\n') {
+            writeln!(file)?;
+        }
+
+        file.write_all(&stubs)?;
+        Ok(())
+    }
+
+    /// Writes a `Baseline` recording the current issues to the specified file.
+    ///
+    /// # Errors
+    /// Returns `CliError::Lingora` if the file cannot be written.
+    pub fn write_baseline(&self, path: &Path) -> Result<(), CliError> {
+        let baseline = Baseline::from_issues(self.audit_result.issues());
+        baseline.save(path)?;
+        Ok(())
+    }
+
+    /// Reads the `Baseline` in the specified file, and removes the issues it records from
+    /// the audit result, so that only new issues are reported and affect the exit status.
+    ///
+    /// # Errors
+    /// Returns `CliError::Lingora` if the file cannot be read, or is not a baseline.
+    pub fn apply_baseline(&mut self, path: &Path) -> Result<(), CliError> {
+        let baseline = Baseline::load(path)?;
+        let fixed = self.audit_result.apply_baseline(&baseline);
+        self.baseline = Some(BaselineSummary {
+            recorded: baseline.len(),
+            fixed,
+        });
+        Ok(())
+    }
+
+    /// Writes a summary of the baseline applied, if any, to the given writer; the number of
+    /// recorded issues still present, and the fingerprint of each recorded issue since fixed.
+    ///
+    /// # Errors
+    /// Returns `CliError::Io` if writing to the output fails.
+    pub fn output_baseline_summary<W: io::Write>(&self, out: &mut W) -> Result<(), CliError> {
+        if let Some(summary) = &self.baseline {
+            let remaining = summary.recorded - summary.fixed.len();
+            writeln!(out, "baseline: {remaining} known issue(s) not reported")?;
+
+            if !summary.fixed.is_empty() {
+                writeln!(
+                    out,
+                    "baseline: {} issue(s) fixed; consider updating the baseline",
+                    summary.fixed.len()
+                )?;
+                summary
+                    .fixed
+                    .iter()
+                    .try_for_each(|fingerprint| writeln!(out, "  fixed: {fingerprint}"))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the issues that are new since the `previous` run of the audit, with their
+    /// locations, and those that have since been resolved, to the given writer.
+    ///
+    /// Issues are matched by their fingerprint, so an issue that has only moved within its
+    /// file is neither new nor resolved.
+    ///
+    /// # Errors
+    /// Returns `CliError::Io` if writing to the output fails.
+    pub fn output_changes_since<W: io::Write>(
+        &self,
+        previous: &App,
+        out: &mut W,
+    ) -> Result<(), CliError> {
+        let fingerprints = |app: &App| {
+            HashSet::<String>::from_iter(app.audit_result.issues().map(AuditIssue::fingerprint))
+        };
+        let before = fingerprints(previous);
+        let after = fingerprints(self);
+
+        let mut new = Vec::from_iter(
+            self.audit_result
+                .issues()
+                .filter(|issue| !before.contains(&issue.fingerprint())),
+        );
+        let mut resolved = Vec::from_iter(
+            previous
+                .audit_result
+                .issues()
+                .filter(|issue| !after.contains(&issue.fingerprint())),
+        );
+
+        let order = |a: &&AuditIssue, b: &&AuditIssue| {
+            (a.kind(), a.subject(), a.message()).cmp(&(b.kind(), b.subject(), b.message()))
+        };
+        new.sort_by(order);
+        new.dedup_by(|a, b| a.fingerprint() == b.fingerprint());
+        resolved.sort_by(order);
+        resolved.dedup_by(|a, b| a.fingerprint() == b.fingerprint());
+
+        writeln!(
+            out,
+            "watch: {} new issue(s), {} resolved issue(s)",
+            new.len(),
+            resolved.len()
+        )?;
+
+        new.iter().try_for_each(|issue| {
+            let (severity, subject) = (issue.severity(), issue.subject());
+            writeln!(out, "  + {severity}: {subject}: {issue}")?;
+            issue
+                .locations()
+                .iter()
+                .try_for_each(|location| writeln!(out, "{:6}--> {location}", ""))
+        })?;
+
+        resolved.iter().try_for_each(|issue| {
+            let (severity, subject) = (issue.severity(), issue.subject());
+            writeln!(out, "  - {severity}: {subject}: {issue}")
+        })?;
+
+        Ok(())
+    }
+
     /// Returns `Ok(())` if the audit found **no issues**, otherwise returns
     /// `Err(CliError::IntegrityErrorsDetected)`.
     ///
@@ -68,6 +275,25 @@ impl App {
     }
 }
 
+impl App {
+    /// Audits the sources of the settings, as `App::try_from`, reusing and updating the
+    /// cache, e.g. as kept between the audits of watch mode.
+    ///
+    /// # Errors
+    /// Returns `CliError::Lingora` if the sources cannot be read, or the cache written.
+    pub fn with_cache(settings: &LingoraToml, cache: &mut ParseCache) -> Result<Self, CliError> {
+        let settings = settings.clone();
+        let engine = AuditEngine::try_from(&settings)?;
+        let audit_result = engine.run_with_cache(cache)?;
+
+        Ok(Self {
+            settings,
+            audit_result,
+            baseline: None,
+        })
+    }
+}
+
 impl TryFrom<&LingoraToml> for App {
     type Error = CliError;
 
@@ -79,6 +305,7 @@ impl TryFrom<&LingoraToml> for App {
         Ok(Self {
             settings,
             audit_result,
+            baseline: None,
         })
     }
 }
@@ -112,6 +339,26 @@ mod test {
         String::from_utf8_lossy(bytes).to_string()
     }
 
+    fn do_output_json(settings: &LingoraToml) -> String {
+        let mut out = Vec::new();
+
+        let app = App::try_from(settings).unwrap();
+
+        app.output_json_report(&mut out).unwrap();
+
+        String::from_utf8_lossy(&out).to_string()
+    }
+
+    fn do_output_junit(settings: &LingoraToml) -> String {
+        let mut out = Vec::new();
+
+        let app = App::try_from(settings).unwrap();
+
+        app.output_junit_report(&mut out).unwrap();
+
+        String::from_utf8_lossy(&out).to_string()
+    }
+
     fn with_filters(f: impl FnOnce()) {
         let mut settings = insta::Settings::clone_current();
         let manifest_dir = regex::escape(env!("CARGO_MANIFEST_DIR"));
@@ -170,18 +417,409 @@ primaries = ["fr-FR", "it-IT", "sr-Cyrl-RS"]
             Variant:   en-AU - Ok
             Language:  fr
             Primary:   fr-FR
-                       missing translation 'en'
-                       missing translation 'en-AU'
-                       missing translation 'en-GB'
+                       error: missing translation 'en'
+                         --> ../core/tests/data/i18n/en/en-GB.ftl:1:1
+                       error: missing translation 'en-AU'
+                         --> ../core/tests/data/i18n/en/en-GB.ftl:2:1
+                       error: missing translation 'en-GB'
+                         --> ../core/tests/data/i18n/en/en-GB.ftl:3:1
             Language:  it
             Primary:   it-IT - Ok
             Language:  sr
             Primary:   sr-Cyrl-RS
-                       missing translation 'en-GB'
-                       redundant translation '-en-GB'
+                       error: missing translation 'en-GB'
+                         --> ../core/tests/data/i18n/en/en-GB.ftl:3:1
+                       error: redundant translation '-en-GB'
+                         --> ../core/tests/data/i18n/sr-Cyrl/sr-Cyrl-RS.ftl:3:1
+            Variant:   sr-Cyrl-BA - Ok
+            ");
+        });
+    }
+
+    #[test]
+    fn app_will_output_warnings_and_exit_ok_when_no_errors() {
+        let settings = LingoraToml::from_str(
+            r#"
+[lingora]
+fluent_sources = ["../core/tests/data/i18n/en", "../core/tests/data/i18n/sr-Cyrl"]
+canonical = "en-GB"
+primaries = ["sr-Cyrl-RS"]
+
+[severity]
+missing_translation = "warning"
+redundant_translation = "off"
+"#,
+        )
+        .unwrap();
+
+        let result = do_output_analysis(&settings);
+
+        with_filters(|| {
+            insta::assert_snapshot!(result, @r"
+            Language:  en
+            Canonical: en-GB - Ok
+            Variant:   en-AU - Ok
+            Language:  sr
+            Primary:   sr-Cyrl-RS
+                       warning: missing translation 'en-GB'
+                         --> ../core/tests/data/i18n/en/en-GB.ftl:3:1
             Variant:   sr-Cyrl-BA - Ok
             ");
         });
+
+        let app = App::try_from(&settings).unwrap();
+        assert!(app.exit_status().is_ok());
+    }
+
+    #[test]
+    fn app_will_report_only_issues_not_in_baseline() {
+        let settings = LingoraToml::from_str(
+            r#"
+[lingora]
+fluent_sources = ["../core/tests/data/i18n"]
+canonical = "en-GB"
+primaries = ["fr-FR", "it-IT", "sr-Cyrl-RS"]
+"#,
+        )
+        .unwrap();
+
+        let path = create_temp_filepath();
+        App::try_from(&settings)
+            .unwrap()
+            .write_baseline(&path)
+            .unwrap();
+
+        let baseline = fs::read_to_string(&path).unwrap();
+        let baseline = baseline.replace(
+            r#""fingerprints": ["#,
+            r#""fingerprints": [
+    "missing_translation|fr-FR :: fixed|missing translation 'fixed'","#,
+        );
+        fs::write(&path, baseline).unwrap();
+
+        let mut app = App::try_from(&settings).unwrap();
+        assert!(app.exit_status().is_err());
+
+        app.apply_baseline(&path).unwrap();
+        assert!(app.exit_status().is_ok());
+
+        let mut out = Vec::new();
+        app.output_audit_report(&mut out).unwrap();
+        app.output_baseline_summary(&mut out).unwrap();
+        let result = String::from_utf8_lossy(&out).to_string();
+
+        with_filters(|| {
+            insta::assert_snapshot!(result, @r"
+            Language:  en
+            Canonical: en-GB - Ok
+            Variant:   en-AU - Ok
+            Language:  fr
+            Primary:   fr-FR - Ok
+            Language:  it
+            Primary:   it-IT - Ok
+            Language:  sr
+            Primary:   sr-Cyrl-RS - Ok
+            Variant:   sr-Cyrl-BA - Ok
+            baseline: 5 known issue(s) not reported
+            baseline: 1 issue(s) fixed; consider updating the baseline
+              fixed: missing_translation|fr-FR :: fixed|missing translation 'fixed'
+            ");
+        });
+    }
+
+    #[test]
+    fn app_will_append_stubs_for_undefined_identifier_literals() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let ftl_path = dir.path().join("en-GB.ftl");
+        let rust_path = dir.path().join("main.rs");
+
+        fs::write(&ftl_path, "greeting = Hello\n").unwrap();
+        fs::write(
+            &rust_path,
+            "fn main() {\n    t!(\"greeting\");\n    t!(\"farewell\", name: name);\n}\n",
+        )
+        .unwrap();
+
+        let settings = LingoraToml::from_str(&format!(
+            r#"
+[lingora]
+fluent_sources = [{ftl_path:?}]
+canonical = "en-GB"
+
+[dioxus_i18n]
+rust_sources = [{rust_path:?}]
+"#
+        ))
+        .unwrap();
+
+        let app = App::try_from(&settings).unwrap();
+        assert!(app.exit_status().is_err());
+
+        app.extract_stubs(&ftl_path).unwrap();
+
+        let result = fs::read_to_string(&ftl_path)
+            .unwrap()
+            .replace(&rust_path.display().to_string(), "main.rs");
+
+        insta::assert_snapshot!(result, @r"
+        greeting = Hello
+
+        ## main.rs
+
+        # main.rs:3:8
+        farewell = farewell { $name }
+        ");
+
+        let app = App::try_from(&settings).unwrap();
+        assert!(app.exit_status().is_ok());
+    }
+
+    #[test]
+    fn app_will_output_changes_since_previous_run() {
+        let previous = LingoraToml::from_str(
+            r#"
+[lingora]
+fluent_sources = ["../core/tests/data/i18n"]
+canonical = "en-GB"
+primaries = ["it-IT", "sr-Cyrl-RS"]
+"#,
+        )
+        .unwrap();
+        let current = LingoraToml::from_str(
+            r#"
+[lingora]
+fluent_sources = ["../core/tests/data/i18n"]
+canonical = "en-GB"
+primaries = ["fr-FR", "it-IT", "sr-Cyrl-RS"]
+
+[severity]
+redundant_translation = "off"
+"#,
+        )
+        .unwrap();
+
+        let previous = App::try_from(&previous).unwrap();
+        let current = App::try_from(&current).unwrap();
+
+        let mut out = Vec::new();
+        current.output_changes_since(&previous, &mut out).unwrap();
+        let result = String::from_utf8_lossy(&out).to_string();
+
+        with_filters(|| {
+            insta::assert_snapshot!(result, @r"
+            watch: 3 new issue(s), 2 resolved issue(s)
+              + error: fr-FR :: en: missing translation 'en'
+                  --> ../core/tests/data/i18n/en/en-GB.ftl:1:1
+              + error: fr-FR :: en-AU: missing translation 'en-AU'
+                  --> ../core/tests/data/i18n/en/en-GB.ftl:2:1
+              + error: fr-FR :: en-GB: missing translation 'en-GB'
+                  --> ../core/tests/data/i18n/en/en-GB.ftl:3:1
+              - error: fr: missing base locale/s for 'fr-FR'
+              - error: sr-Cyrl-RS :: -en-GB: redundant translation '-en-GB'
+            ");
+        });
+    }
+
+    #[test]
+    fn app_will_output_json() {
+        let settings = LingoraToml::from_str(
+            r#"
+[lingora]
+fluent_sources = ["../core/tests/data/i18n/en", "../core/tests/data/i18n/it"]
+canonical = "en-GB"
+primaries = ["it-IT"]
+"#,
+        )
+        .unwrap();
+
+        let result = do_output_json(&settings);
+
+        with_filters(|| {
+            insta::assert_snapshot!(result, @r#"
+            {
+              "schema_version": 1,
+              "ok": true,
+              "canonical": "en-GB",
+              "primaries": [
+                "it-IT"
+              ],
+              "documents": [
+                {
+                  "locale": "en-AU",
+                  "role": "variant",
+                  "paths": [
+                    "../core/tests/data/i18n/en/en-AU.ftl"
+                  ]
+                },
+                {
+                  "locale": "en-GB",
+                  "role": "canonical",
+                  "paths": [
+                    "../core/tests/data/i18n/en/en-GB.ftl"
+                  ]
+                },
+                {
+                  "locale": "it-IT",
+                  "role": "primary",
+                  "paths": [
+                    "../core/tests/data/i18n/it/it-IT.ftl"
+                  ]
+                }
+              ],
+              "issues": []
+            }
+            "#);
+        });
+    }
+
+    #[test]
+    fn app_will_output_junit_when_errors() {
+        let settings = LingoraToml::from_str(
+            r#"
+[lingora]
+fluent_sources = ["../core/tests/data/i18n/en", "../core/tests/data/i18n/sr-Cyrl"]
+canonical = "en-GB"
+primaries = ["sr-Cyrl-RS"]
+"#,
+        )
+        .unwrap();
+
+        let result = do_output_junit(&settings);
+
+        with_filters(|| {
+            insta::assert_snapshot!(result, @r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="lingora" tests="95" failures="2" errors="0">
+              <testsuite name="workspace" tests="19" failures="0" errors="0">
+                <testcase name="parse_error" classname="lingora.workspace"/>
+                <testcase name="missing_base" classname="lingora.workspace"/>
+                <testcase name="undefined_base" classname="lingora.workspace"/>
+                <testcase name="duplicate_identifier" classname="lingora.workspace"/>
+                <testcase name="invalid_reference" classname="lingora.workspace"/>
+                <testcase name="missing_translation" classname="lingora.workspace"/>
+                <testcase name="redundant_translation" classname="lingora.workspace"/>
+                <testcase name="signature_mismatch" classname="lingora.workspace"/>
+                <testcase name="untranslated_copy" classname="lingora.workspace"/>
+                <testcase name="impossible_plural_category" classname="lingora.workspace"/>
+                <testcase name="missing_plural_category" classname="lingora.workspace"/>
+                <testcase name="malformed_identifier_literal" classname="lingora.workspace"/>
+                <testcase name="undefined_identifier_literal" classname="lingora.workspace"/>
+                <testcase name="unverifiable_identifier" classname="lingora.workspace"/>
+                <testcase name="unmatched_identifier_pattern" classname="lingora.workspace"/>
+                <testcase name="argument_mismatch" classname="lingora.workspace"/>
+                <testcase name="hardcoded_text" classname="lingora.workspace"/>
+                <testcase name="unused_identifier" classname="lingora.workspace"/>
+                <testcase name="unused_suppression" classname="lingora.workspace"/>
+              </testsuite>
+              <testsuite name="en-GB" tests="19" failures="0" errors="0">
+                <properties>
+                  <property name="role" value="canonical"/>
+                </properties>
+                <testcase name="parse_error" classname="lingora.en-GB"/>
+                <testcase name="missing_base" classname="lingora.en-GB"/>
+                <testcase name="undefined_base" classname="lingora.en-GB"/>
+                <testcase name="duplicate_identifier" classname="lingora.en-GB"/>
+                <testcase name="invalid_reference" classname="lingora.en-GB"/>
+                <testcase name="missing_translation" classname="lingora.en-GB"/>
+                <testcase name="redundant_translation" classname="lingora.en-GB"/>
+                <testcase name="signature_mismatch" classname="lingora.en-GB"/>
+                <testcase name="untranslated_copy" classname="lingora.en-GB"/>
+                <testcase name="impossible_plural_category" classname="lingora.en-GB"/>
+                <testcase name="missing_plural_category" classname="lingora.en-GB"/>
+                <testcase name="malformed_identifier_literal" classname="lingora.en-GB"/>
+                <testcase name="undefined_identifier_literal" classname="lingora.en-GB"/>
+                <testcase name="unverifiable_identifier" classname="lingora.en-GB"/>
+                <testcase name="unmatched_identifier_pattern" classname="lingora.en-GB"/>
+                <testcase name="argument_mismatch" classname="lingora.en-GB"/>
+                <testcase name="hardcoded_text" classname="lingora.en-GB"/>
+                <testcase name="unused_identifier" classname="lingora.en-GB"/>
+                <testcase name="unused_suppression" classname="lingora.en-GB"/>
+              </testsuite>
+              <testsuite name="sr-Cyrl-RS" tests="19" failures="2" errors="0">
+                <properties>
+                  <property name="role" value="primary"/>
+                </properties>
+                <testcase name="parse_error" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="missing_base" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="undefined_base" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="duplicate_identifier" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="invalid_reference" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="missing_translation" classname="lingora.sr-Cyrl-RS">
+                  <failure message="1 missing_translation issue(s)" type="missing_translation">
+            error: sr-Cyrl-RS :: en-GB: missing translation &apos;en-GB&apos;
+              --> ../core/tests/data/i18n/en/en-GB.ftl:3:1
+                  </failure>
+                </testcase>
+                <testcase name="redundant_translation" classname="lingora.sr-Cyrl-RS">
+                  <failure message="1 redundant_translation issue(s)" type="redundant_translation">
+            error: sr-Cyrl-RS :: -en-GB: redundant translation &apos;-en-GB&apos;
+              --> ../core/tests/data/i18n/sr-Cyrl/sr-Cyrl-RS.ftl:3:1
+                  </failure>
+                </testcase>
+                <testcase name="signature_mismatch" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="untranslated_copy" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="impossible_plural_category" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="missing_plural_category" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="malformed_identifier_literal" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="undefined_identifier_literal" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="unverifiable_identifier" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="unmatched_identifier_pattern" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="argument_mismatch" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="hardcoded_text" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="unused_identifier" classname="lingora.sr-Cyrl-RS"/>
+                <testcase name="unused_suppression" classname="lingora.sr-Cyrl-RS"/>
+              </testsuite>
+              <testsuite name="en-AU" tests="19" failures="0" errors="0">
+                <properties>
+                  <property name="role" value="variant"/>
+                </properties>
+                <testcase name="parse_error" classname="lingora.en-AU"/>
+                <testcase name="missing_base" classname="lingora.en-AU"/>
+                <testcase name="undefined_base" classname="lingora.en-AU"/>
+                <testcase name="duplicate_identifier" classname="lingora.en-AU"/>
+                <testcase name="invalid_reference" classname="lingora.en-AU"/>
+                <testcase name="missing_translation" classname="lingora.en-AU"/>
+                <testcase name="redundant_translation" classname="lingora.en-AU"/>
+                <testcase name="signature_mismatch" classname="lingora.en-AU"/>
+                <testcase name="untranslated_copy" classname="lingora.en-AU"/>
+                <testcase name="impossible_plural_category" classname="lingora.en-AU"/>
+                <testcase name="missing_plural_category" classname="lingora.en-AU"/>
+                <testcase name="malformed_identifier_literal" classname="lingora.en-AU"/>
+                <testcase name="undefined_identifier_literal" classname="lingora.en-AU"/>
+                <testcase name="unverifiable_identifier" classname="lingora.en-AU"/>
+                <testcase name="unmatched_identifier_pattern" classname="lingora.en-AU"/>
+                <testcase name="argument_mismatch" classname="lingora.en-AU"/>
+                <testcase name="hardcoded_text" classname="lingora.en-AU"/>
+                <testcase name="unused_identifier" classname="lingora.en-AU"/>
+                <testcase name="unused_suppression" classname="lingora.en-AU"/>
+              </testsuite>
+              <testsuite name="sr-Cyrl-BA" tests="19" failures="0" errors="0">
+                <properties>
+                  <property name="role" value="variant"/>
+                </properties>
+                <testcase name="parse_error" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="missing_base" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="undefined_base" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="duplicate_identifier" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="invalid_reference" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="missing_translation" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="redundant_translation" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="signature_mismatch" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="untranslated_copy" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="impossible_plural_category" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="missing_plural_category" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="malformed_identifier_literal" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="undefined_identifier_literal" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="unverifiable_identifier" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="unmatched_identifier_pattern" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="argument_mismatch" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="hardcoded_text" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="unused_identifier" classname="lingora.sr-Cyrl-BA"/>
+                <testcase name="unused_suppression" classname="lingora.sr-Cyrl-BA"/>
+              </testsuite>
+            </testsuites>
+            "#);
+        });
     }
 
     fn create_temp_filepath() -> TempPath {
@@ -11,6 +11,15 @@ pub enum OutputMode {
     Silent,
     /// Output analysis report details to stdout.
     Standard,
+    /// Output the audit result to stdout as JSON, for consumption by other tools.
+    /// See `docs/json_output.md` for the schema.
+    Json,
+    /// Output the audit result to stdout as a SARIF 2.1.0 log, e.g. for upload as
+    /// code-scanning alerts.
+    Sarif,
+    /// Output the audit result to stdout as a JUnit XML report, with a test suite per
+    /// locale and a test case per kind of issue.
+    Junit,
 }
 
 /// Command-line arguments specific to the `lingora-cli` binary.
@@ -18,6 +27,9 @@ pub enum OutputMode {
 /// Extends the shared `CoreArgs` (from `lingora-core`) with CLI-only options:
 /// - Output verbosity/behavior
 /// - Optional generation of `dioxus_i18n::I18nConfig` Rust code
+/// - Optional reporting against, or writing of, a baseline of known issues
+/// - Optional extraction of stub entries for undefined identifier literals
+/// - Optional watching of the sources, re-auditing as they change
 #[derive(Debug, Parser)]
 #[command(
     name = env!("CARGO_PKG_NAME"),
@@ -37,6 +49,31 @@ pub struct CliArgs {
     /// See <https://docs.rs/dioxus-i18n/latest/dioxus_i18n/>.
     #[arg(long)]
     dioxus_i18n_config_file: Option<PathBuf>,
+
+    /// If provided, then only issues not recorded in the given baseline file are reported,
+    /// along with a summary of the recorded issues that have since been fixed.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// If provided, then the given baseline file will be created (or replaced), recording
+    /// the current issues, and the audit is reported against it.
+    #[arg(long)]
+    write_baseline: Option<PathBuf>,
+
+    /// If provided, then a stub entry is appended to the given Fluent file (usually the
+    /// canonical document) for each identifier literal used in the Rust sources but not
+    /// defined in the canonical document.
+    #[arg(long)]
+    extract: Option<PathBuf>,
+
+    /// If set, then after the audit is reported the sources and configuration file are
+    /// watched, and on each change the audit is re-run and only the issues that are new,
+    /// or have been resolved, since the previous run are reported.
+    #[arg(
+        long,
+        conflicts_with_all = ["output_mode", "dioxus_i18n_config_file", "write_baseline", "extract"]
+    )]
+    watch: bool,
 }
 
 impl CliArgs {
@@ -50,6 +87,26 @@ impl CliArgs {
         self.dioxus_i18n_config_file.as_deref()
     }
 
+    /// Returns the path of the baseline file against which issues are reported.
+    pub fn baseline(&self) -> Option<&Path> {
+        self.baseline.as_deref()
+    }
+
+    /// Returns the path where a baseline of the current issues should be written.
+    pub fn write_baseline(&self) -> Option<&Path> {
+        self.write_baseline.as_deref()
+    }
+
+    /// Returns the path of the Fluent file to which stub entries should be appended.
+    pub fn extract(&self) -> Option<&Path> {
+        self.extract.as_deref()
+    }
+
+    /// Returns `true` if the sources should be watched and re-audited as they change.
+    pub fn watch(&self) -> bool {
+        self.watch
+    }
+
     /// Returns the selected output mode.
     pub fn output_mode(&self) -> &OutputMode {
         &self.output_mode
@@ -4,27 +4,106 @@ use std::process::ExitCode;
 
 use clap::Parser;
 use lingora_cli::{App, CliArgs, CliError, OutputMode};
+use lingora_core::prelude::{LingoraToml, ParseCache, SourceWatcher};
 
 #[coverage(off)]
 fn run() -> Result<(), CliError> {
     let args = CliArgs::parse();
+    let settings = LingoraToml::try_from(args.core_args())?;
 
-    let app = App::try_from(&args)?;
+    // Watch mode keeps the parsed files of the first audit for the audits that follow...
+    let mut cache = ParseCache::default();
+    let mut app = if args.watch() {
+        App::with_cache(&settings, &mut cache)?
+    } else {
+        App::try_from(&settings)?
+    };
 
     if let Some(path) = args.dioxus_i18n_config_file() {
         app.output_dioxus_i18n_config(path)?;
     }
 
+    if let Some(path) = args.extract() {
+        app.extract_stubs(path)?;
+    }
+
+    if let Some(path) = args.write_baseline() {
+        app.write_baseline(path)?;
+    }
+
+    if let Some(path) = args.baseline().or(args.write_baseline()) {
+        app.apply_baseline(path)?;
+    }
+
     match args.output_mode() {
-        OutputMode::Silent => app.exit_status(),
+        OutputMode::Silent => {}
+        OutputMode::Standard => app.output_audit_report(&mut std::io::stdout())?,
+        OutputMode::Json => app.output_json_report(&mut std::io::stdout())?,
+        OutputMode::Sarif => app.output_sarif_report(
+            args.core_args().config_file().as_deref(),
+            &mut std::io::stdout(),
+        )?,
+        OutputMode::Junit => app.output_junit_report(&mut std::io::stdout())?,
+    }
+
+    app.output_baseline_summary(&mut std::io::stderr())?;
+
+    if args.watch() {
+        watch(&args, settings, cache, app)
+    } else {
+        app.exit_status()
+    }
+}
 
-        OutputMode::Standard => {
-            app.output_audit_report(&mut std::io::stdout())?;
-            app.exit_status()
+/// Re-runs the audit whenever the sources or configuration file change, reporting the
+/// issues new or resolved since the previous run, until interrupted.
+///
+/// Errors in re-running the audit, e.g. an invalid `Lingora.toml` part way through
+/// being edited, are reported and the sources watched again.
+///
+/// The parsed files, and the documents built from them, are kept between runs, starting
+/// with those of the initial audit, so that only those that have changed are parsed and
+/// rebuilt.
+#[coverage(off)]
+fn watch(
+    args: &CliArgs,
+    mut settings: LingoraToml,
+    mut cache: ParseCache,
+    mut app: App,
+) -> Result<(), CliError> {
+    let config_file = args.core_args().config_file();
+    let mut watcher = SourceWatcher::new(&settings, config_file.as_deref())?;
+
+    loop {
+        watcher.wait()?;
+
+        match rerun(args, &mut cache) {
+            Ok((rerun_settings, rerun_app)) => {
+                rerun_app.output_changes_since(&app, &mut std::io::stdout())?;
+                app = rerun_app;
+
+                if rerun_settings != settings {
+                    settings = rerun_settings;
+                    watcher = SourceWatcher::new(&settings, config_file.as_deref())?;
+                }
+            }
+            Err(error) => eprintln!("error: {error}"),
         }
     }
 }
 
+#[coverage(off)]
+fn rerun(args: &CliArgs, cache: &mut ParseCache) -> Result<(LingoraToml, App), CliError> {
+    let settings = LingoraToml::try_from(args.core_args())?;
+    let mut app = App::with_cache(&settings, cache)?;
+
+    if let Some(path) = args.baseline() {
+        app.apply_baseline(path)?;
+    }
+
+    Ok((settings, app))
+}
+
 #[coverage(off)]
 fn main() -> ExitCode {
     match run() {
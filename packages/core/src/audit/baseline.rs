@@ -0,0 +1,168 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{audit::AuditIssue, error::LingoraError};
+
+/// A snapshot of the issues found by an audit, recorded by their `AuditIssue::fingerprint`,
+/// so that later audits need only report issues that have since been introduced.
+///
+/// Stored as JSON, with the fingerprints sorted so that the file diffs cleanly. An issue
+/// found more than once appears once for each occurrence.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    schema_version: u32,
+    fingerprints: Vec<String>,
+}
+
+impl Baseline {
+    /// The version of the baseline file format.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Constructs a `Baseline` recording the given issues.
+    pub fn from_issues<'a>(issues: impl IntoIterator<Item = &'a AuditIssue>) -> Self {
+        let mut fingerprints = Vec::from_iter(issues.into_iter().map(AuditIssue::fingerprint));
+        fingerprints.sort();
+
+        Self {
+            schema_version: Self::SCHEMA_VERSION,
+            fingerprints,
+        }
+    }
+
+    /// Reads a baseline previously written by `Baseline::save`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, is not a baseline, or was written
+    /// in an unsupported format version.
+    pub fn load(path: &Path) -> Result<Self, LingoraError> {
+        let content = fs::read_to_string(path)?;
+        let baseline = serde_json::from_str::<Self>(&content)?;
+
+        if baseline.schema_version != Self::SCHEMA_VERSION {
+            return Err(LingoraError::UnsupportedBaseline(baseline.schema_version));
+        }
+
+        Ok(baseline)
+    }
+
+    /// Writes the baseline to the given path, replacing any existing file.
+    pub fn save(&self, path: &Path) -> Result<(), LingoraError> {
+        let mut content = serde_json::to_string_pretty(self)?;
+        content.push('\n');
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Returns the number of issues recorded in the baseline.
+    pub fn len(&self) -> usize {
+        self.fingerprints.len()
+    }
+
+    /// Returns `true` if the baseline records no issues.
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+
+    /// Splits the issues into those not recorded in the baseline, and returns them along
+    /// with the fingerprints of the recorded issues that were not found, i.e. those fixed
+    /// since the baseline was written.
+    pub(crate) fn partition(&self, issues: Vec<AuditIssue>) -> (Vec<AuditIssue>, Vec<String>) {
+        let mut remaining =
+            self.fingerprints
+                .iter()
+                .fold(HashMap::<&str, usize>::new(), |mut acc, fingerprint| {
+                    *acc.entry(fingerprint).or_default() += 1;
+                    acc
+                });
+
+        let issues = Vec::from_iter(issues.into_iter().filter(|issue| {
+            let fingerprint = issue.fingerprint();
+            match remaining.get_mut(fingerprint.as_str()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            }
+        }));
+
+        let mut fixed = Vec::new();
+        self.fingerprints.iter().for_each(|fingerprint| {
+            if let Some(count) = remaining.get_mut(fingerprint.as_str())
+                && *count > 0
+            {
+                *count -= 1;
+                fixed.push(fingerprint.clone());
+            }
+        });
+
+        (issues, fixed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_support::{identifier, locale};
+
+    fn issues() -> Vec<AuditIssue> {
+        vec![
+            AuditIssue::missing_translation(&locale("fr-FR"), &identifier("greeting")),
+            AuditIssue::redundant_translation(&locale("fr-FR"), &identifier("farewell")),
+            AuditIssue::redundant_translation(&locale("fr-FR"), &identifier("farewell")),
+        ]
+    }
+
+    #[test]
+    fn baseline_will_round_trip_through_a_file() {
+        let baseline = Baseline::from_issues(&issues());
+        let file = tempfile::NamedTempFile::new().expect("temp file");
+
+        baseline.save(file.path()).expect("baseline saved");
+        let loaded = Baseline::load(file.path()).expect("baseline loaded");
+
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.len(), 3);
+    }
+
+    #[test]
+    fn baseline_will_reject_unsupported_versions() {
+        let file = tempfile::NamedTempFile::new().expect("temp file");
+        fs::write(
+            file.path(),
+            r#"{ "schema_version": 99, "fingerprints": [] }"#,
+        )
+        .unwrap();
+
+        let result = Baseline::load(file.path());
+
+        assert!(matches!(result, Err(LingoraError::UnsupportedBaseline(99))));
+    }
+
+    #[test]
+    fn baseline_will_remove_recorded_issues_and_report_fixed_issues() {
+        let baseline = Baseline::from_issues(&issues()[1..]);
+
+        let mut current = issues();
+        current.pop();
+        current.push(AuditIssue::untranslated_copy(
+            &locale("fr-FR"),
+            &identifier("greeting"),
+        ));
+
+        let (new, fixed) = baseline.partition(current);
+
+        assert_eq!(
+            Vec::from_iter(new.iter().map(AuditIssue::fingerprint)),
+            [
+                "missing_translation|fr-FR :: greeting|missing translation 'greeting'",
+                "untranslated_copy|fr-FR :: greeting|untranslated copy 'greeting'",
+            ]
+        );
+        assert_eq!(
+            fixed,
+            ["redundant_translation|fr-FR :: farewell|redundant translation 'farewell'"]
+        );
+    }
+}
@@ -3,8 +3,11 @@ use std::{ffi::OsStr, path::PathBuf};
 use walkdir::WalkDir;
 
 use crate::{
-    audit::{AuditResult, Pipeline, Workspace},
-    config::LingoraToml,
+    audit::{
+        AuditResult, ParseCache, Pipeline, Workspace,
+        pipeline::{Audited, Empty},
+    },
+    config::{AuditSettings, LingoraToml, RustScanSettings, SeveritySettings},
     error::LingoraError,
     fluent::FluentFile,
     rust::RustFile,
@@ -17,9 +20,16 @@ use crate::{
 /// 2. Building a `Workspace` model
 /// 3. Running the analysis `Pipeline` (parsing → document collection → classification → auditing)
 /// 4. Returning a complete `AuditResult` containing issues and classified documents
+///
+/// If a cache file is configured, only the files changed since the previous audit are
+/// parsed, the others being restored from the `ParseCache`.
 #[derive(Debug)]
 pub struct AuditEngine {
     workspace: Workspace,
+    settings: AuditSettings,
+    rust_scan: RustScanSettings,
+    severity: SeveritySettings,
+    cache: Option<PathBuf>,
 }
 
 impl AuditEngine {
@@ -31,9 +41,57 @@ impl AuditEngine {
     /// - Classify documents as Canonical / Primary / Variant / Orphan
     /// - Compare canonical vs targets (missing keys, redundants, signatures, etc.)
     /// - Validate Rust macro usage against canonical identifiers
+    /// - Optionally, report canonical identifiers that are never used
+    /// - Apply the configured severity to each issue, dropping those turned off
     ///
     /// Returns `Ok(AuditResult)` on success, even if issues are found (use `AuditResult::is_ok()` to check cleanliness).
+    ///
+    /// The configured cache file, if any, is read before, and rewritten after, the audit.
     pub fn run(&self) -> Result<AuditResult, LingoraError> {
+        match &self.cache {
+            Some(_) => self.run_with_cache(&mut ParseCache::default()),
+            None => {
+                let workspace = &self.workspace;
+                let audit_result = self.audit(self.pipeline())?.get_result(workspace);
+                Ok(audit_result)
+            }
+        }
+    }
+
+    /// Executes the audit as `AuditEngine::run`, reusing and updating the given cache; an
+    /// empty cache is first read from the configured cache file, if any, which is then
+    /// rewritten.
+    ///
+    /// Long-running callers, e.g. those re-auditing as the sources change, keep the cache
+    /// between audits, so that the documents of the locales whose files are unchanged are
+    /// reused along with their analysis, rather than rebuilt.
+    pub fn run_with_cache(&self, cache: &mut ParseCache) -> Result<AuditResult, LingoraError> {
+        let workspace = &self.workspace;
+
+        if let Some(path) = &self.cache
+            && cache.is_empty()
+        {
+            *cache = ParseCache::load(path);
+        }
+
+        let mut audited = self.audit(self.pipeline().with_cache(std::mem::take(cache)))?;
+        *cache = audited.take_cache();
+
+        if let Some(path) = &self.cache {
+            cache.save(path)?;
+        }
+
+        Ok(audited.get_result(workspace))
+    }
+
+    fn pipeline(&self) -> Pipeline<Empty> {
+        Pipeline::with_settings(&self.settings)
+            .with_severity(&self.severity)
+            .with_rust_scan(&self.rust_scan)
+    }
+
+    /// Runs the pipeline's steps, as listed for `AuditEngine::run`.
+    fn audit(&self, pipeline: Pipeline<Empty>) -> Result<Pipeline<Audited>, LingoraError> {
         let workspace = &self.workspace;
 
         let fluent_files = workspace.fluent_files();
@@ -42,14 +100,13 @@ impl AuditEngine {
         let canonical_locale = workspace.canonical_locale();
         let primary_locales = Vec::from_iter(workspace.primary_locales().cloned());
 
-        let audit_result = Pipeline::default()
+        let audited = pipeline
             .parse_files(fluent_files, rust_files)?
             .collect_documents_by_locale()
             .classify_documents(canonical_locale, &primary_locales)
-            .audit()
-            .get_result(workspace);
+            .audit();
 
-        Ok(audit_result)
+        Ok(audited)
     }
 }
 
@@ -65,8 +122,18 @@ impl TryFrom<&LingoraToml> for AuditEngine {
         let rust_files = collate_rust_files(&settings.dioxus_i18n.rust_sources)?;
 
         let workspace = Workspace::new(fluent_files, canonical, primaries, rust_files);
-
-        Ok(AuditEngine { workspace })
+        let rust_scan = settings.rust_scan.clone();
+        let severity = settings.severity.clone();
+        let cache = settings.lingora.cache.clone();
+        let settings = settings.audit.clone();
+
+        Ok(AuditEngine {
+            workspace,
+            settings,
+            rust_scan,
+            severity,
+            cache,
+        })
     }
 }
 
@@ -1,12 +1,18 @@
 use std::path::PathBuf;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer, ser::SerializeStruct};
+
 use crate::{
-    domain::{LanguageRoot, Locale},
-    fluent::{ParsedFluentFile, QualifiedIdentifier},
-    rust::ParsedRustFile,
+    config::Severity,
+    domain::{LanguageRoot, Locale, SourceLocation},
+    error::LingoraError,
+    fluent::{ParsedFluentFile, QualifiedIdentifier, SignatureDiff},
+    rust::{HardcodedText, MacroCall, ParsedRustFile},
 };
 
 /// Classification of the kind of localization / translation problems found during audit.
+///
+/// Serialized as its `id`, e.g. `missing_translation`.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Kind {
     /// Parsing of a `.ftl` or `.rs` file failed (syntax error, invalid structure, etc)
@@ -37,6 +43,18 @@ pub enum Kind {
     /// primary and variant, e.g. different number or names of variables.
     SignatureMismatch,
 
+    /// A primary's entry has the same value and attributes as the canonical entry, i.e.
+    /// it has not been translated.
+    UntranslatedCopy,
+
+    /// A select expression on a number has a variant for a plural category that the
+    /// locale's CLDR plural rules can never select, e.g. `[few]` in English.
+    ImpossiblePluralCategory,
+
+    /// A select expression on a number has no variant for a plural category of the
+    /// locale's CLDR plural rules, e.g. `[many]` in Polish.
+    MissingPluralCategory,
+
     /// A string literal used in a `t!`, `te!`, or `tid!` macro does not conform to
     /// valid Fluent identifier syntax.
     MalformedIdentifierLiteral,
@@ -44,6 +62,171 @@ pub enum Kind {
     /// A string literal used in a `dioxus_i18n` macro refers to an identifier that
     /// does **not** exist in the canonical Fluent document.
     UndefinedIdentifierLiteral,
+
+    /// The identifier passed to a translation macro, function or method is a path, e.g.
+    /// `TITLE_KEY`, that cannot be resolved to a `const` or `static` string item, so it
+    /// cannot be verified. Reported as `Severity::Info` by default.
+    UnverifiableIdentifier,
+
+    /// A `format!` identifier pattern, e.g. `status-*` for `format!("status-{}", s)`,
+    /// matches no identifier in the canonical Fluent document.
+    UnmatchedIdentifierPattern,
+
+    /// The named arguments passed to a `dioxus_i18n` macro differ from the variables of
+    /// the canonical message, e.g. `count` is passed but the message uses `$num`.
+    ArgumentMismatch,
+
+    /// A string literal is used as text, or as a user-visible attribute's value, in an
+    /// `rsx!` call, rather than a translation.
+    HardcodedText,
+
+    /// A message, or message attribute, in the canonical document is never used by the
+    /// Rust sources, nor referenced by any other message.
+    UnusedIdentifier,
+
+    /// A `lingora-ignore` suppression directive did not silence any issue.
+    UnusedSuppression,
+}
+
+impl Kind {
+    /// Every kind of issue, in declaration order.
+    pub const ALL: [Kind; 19] = [
+        Kind::ParseError,
+        Kind::MissingBase,
+        Kind::UndefinedBase,
+        Kind::DuplicateIdentifier,
+        Kind::InvalidReference,
+        Kind::MissingTranslation,
+        Kind::RedundantTranslation,
+        Kind::SignatureMismatch,
+        Kind::UntranslatedCopy,
+        Kind::ImpossiblePluralCategory,
+        Kind::MissingPluralCategory,
+        Kind::MalformedIdentifierLiteral,
+        Kind::UndefinedIdentifierLiteral,
+        Kind::UnverifiableIdentifier,
+        Kind::UnmatchedIdentifierPattern,
+        Kind::ArgumentMismatch,
+        Kind::HardcodedText,
+        Kind::UnusedIdentifier,
+        Kind::UnusedSuppression,
+    ];
+
+    /// A stable, `snake_case` identifier for the kind, e.g. `missing_translation`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Kind::ParseError => "parse_error",
+            Kind::MissingBase => "missing_base",
+            Kind::UndefinedBase => "undefined_base",
+            Kind::DuplicateIdentifier => "duplicate_identifier",
+            Kind::InvalidReference => "invalid_reference",
+            Kind::MissingTranslation => "missing_translation",
+            Kind::RedundantTranslation => "redundant_translation",
+            Kind::SignatureMismatch => "signature_mismatch",
+            Kind::UntranslatedCopy => "untranslated_copy",
+            Kind::ImpossiblePluralCategory => "impossible_plural_category",
+            Kind::MissingPluralCategory => "missing_plural_category",
+            Kind::MalformedIdentifierLiteral => "malformed_identifier_literal",
+            Kind::UndefinedIdentifierLiteral => "undefined_identifier_literal",
+            Kind::UnverifiableIdentifier => "unverifiable_identifier",
+            Kind::UnmatchedIdentifierPattern => "unmatched_identifier_pattern",
+            Kind::ArgumentMismatch => "argument_mismatch",
+            Kind::HardcodedText => "hardcoded_text",
+            Kind::UnusedIdentifier => "unused_identifier",
+            Kind::UnusedSuppression => "unused_suppression",
+        }
+    }
+
+    /// A one sentence, human-readable description of the kind.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Kind::ParseError => "A Fluent or Rust source file could not be parsed.",
+            Kind::MissingBase => "No translation files were found for a required locale.",
+            Kind::UndefinedBase => {
+                "Locales exist for a language root that has no canonical or primary locale."
+            }
+            Kind::DuplicateIdentifier => "An identifier is defined more than once in a locale.",
+            Kind::InvalidReference => "A message or term references an undefined identifier.",
+            Kind::MissingTranslation => "A canonical entry has no translation in a primary locale.",
+            Kind::RedundantTranslation => {
+                "An entry is not present in the canonical or primary locale it derives from."
+            }
+            Kind::SignatureMismatch => {
+                "An entry's variables or attributes differ from those of its base locale."
+            }
+            Kind::UntranslatedCopy => "A primary entry is an untranslated copy of the canonical.",
+            Kind::ImpossiblePluralCategory => {
+                "A plural variant's category is never selected by the locale's plural rules."
+            }
+            Kind::MissingPluralCategory => {
+                "A plural category of the locale's plural rules has no variant."
+            }
+            Kind::MalformedIdentifierLiteral => {
+                "A translation macro's literal is not a valid Fluent identifier."
+            }
+            Kind::UndefinedIdentifierLiteral => {
+                "A translation macro's literal is not defined in the canonical locale."
+            }
+            Kind::UnverifiableIdentifier => {
+                "A translation call's identifier argument cannot be resolved to a literal."
+            }
+            Kind::UnmatchedIdentifierPattern => {
+                "A translation call's format! pattern matches no canonical identifier."
+            }
+            Kind::ArgumentMismatch => {
+                "A translation macro's arguments differ from the canonical message's variables."
+            }
+            Kind::HardcodedText => "An rsx! text node or visible attribute is not translated.",
+            Kind::UnusedIdentifier => "A canonical message or attribute is never used.",
+            Kind::UnusedSuppression => "A suppression directive does not silence any issue.",
+        }
+    }
+
+    /// Other names by which the kind may be given, e.g. in a suppression directive.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Kind::MalformedIdentifierLiteral => &["malformed_identifier"],
+            Kind::UndefinedIdentifierLiteral => &["undefined_identifier"],
+            _ => &[],
+        }
+    }
+
+    /// The severity of the kind unless configured otherwise; kinds that are informational,
+    /// or that reflect a locale's plural rules rather than a broken lookup, are not errors.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            Kind::UnverifiableIdentifier => Severity::Info,
+            Kind::ImpossiblePluralCategory | Kind::MissingPluralCategory => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl std::str::FromStr for Kind {
+    type Err = LingoraError;
+
+    /// Parses a kind from its `id`, or from one of its shorter aliases, e.g.
+    /// `undefined_identifier` for `undefined_identifier_literal`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Kind::ALL
+            .iter()
+            .find(|kind| kind.id() == s || kind.aliases().contains(&s))
+            .cloned()
+            .ok_or_else(|| LingoraError::UnknownKind(s.into()))
+    }
+}
+
+impl Serialize for Kind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        id.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 /// The entity affected by or associated with an `AuditIssue`.
@@ -82,29 +265,109 @@ impl std::fmt::Display for Subject {
     }
 }
 
+/// Serialized as an object whose `type` field names the variant, e.g.
+/// `{ "type": "entry", "locale": "fr-FR", "identifier": "greeting" }`.
+impl Serialize for Subject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Subject::FluentFile(path) => {
+                let mut state = serializer.serialize_struct("Subject", 2)?;
+                state.serialize_field("type", "fluent_file")?;
+                state.serialize_field("path", path)?;
+                state.end()
+            }
+            Subject::RustFile(path) => {
+                let mut state = serializer.serialize_struct("Subject", 2)?;
+                state.serialize_field("type", "rust_file")?;
+                state.serialize_field("path", path)?;
+                state.end()
+            }
+            Subject::Locale(locale) => {
+                let mut state = serializer.serialize_struct("Subject", 2)?;
+                state.serialize_field("type", "locale")?;
+                state.serialize_field("locale", locale)?;
+                state.end()
+            }
+            Subject::Entry(locale, identifier) => {
+                let mut state = serializer.serialize_struct("Subject", 3)?;
+                state.serialize_field("type", "entry")?;
+                state.serialize_field("locale", locale)?;
+                state.serialize_field("identifier", identifier)?;
+                state.end()
+            }
+            Subject::LanguageRoot(root) => {
+                let mut state = serializer.serialize_struct("Subject", 2)?;
+                state.serialize_field("type", "language_root")?;
+                state.serialize_field("language_root", root)?;
+                state.end()
+            }
+        }
+    }
+}
+
 /// A single localization problem discovered during Fluent/Rust source analysis.
 ///
 /// Each issue has:
 /// - a `Kind` (what kind of problem)
 /// - a `Subject` (what entity is affected)
 /// - a human-readable `message` (for display in CLI/TUI/reports)
-#[derive(Clone, Debug)]
+/// - zero or more source `locations` where the problem can be seen
+/// - a `severity`, as configured for its kind
+/// - for a signature mismatch, the `SignatureDiff` describing it
+/// - for an undefined identifier or reference, the nearest defined identifiers
+#[derive(Clone, Debug, Serialize)]
 pub struct AuditIssue {
     kind: Kind,
+    severity: Severity,
     subject: Subject,
     message: String,
+    locations: Vec<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature_diff: Option<SignatureDiff>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
 }
 
 // Constructors...
 impl AuditIssue {
     fn new(kind: Kind, subject: Subject, message: String) -> Self {
+        let severity = kind.default_severity();
+
         Self {
             kind,
+            severity,
             subject,
             message,
+            locations: Vec::new(),
+            signature_diff: None,
+            suggestions: Vec::new(),
         }
     }
 
+    /// Sets the severity of the issue, which is the kind's default severity unless
+    /// configured otherwise.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Attaches the source locations relevant to the issue, e.g. each definition of a
+    /// duplicate identifier.
+    pub fn with_locations<'a, I>(mut self, locations: I) -> Self
+    where
+        I: IntoIterator<Item = &'a SourceLocation>,
+    {
+        self.locations.extend(locations.into_iter().cloned());
+        self
+    }
+
+    /// Attaches the identifiers that were possibly intended, nearest first, e.g. for a
+    /// misspelt identifier literal.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
     /// Fluent file failed to parse (syntax error, invalid AST, etc.).
     pub fn parse_fluent_file_error(file: &ParsedFluentFile) -> Self {
         Self::new(
@@ -112,6 +375,7 @@ impl AuditIssue {
             Subject::FluentFile(file.path().to_path_buf()),
             file.error_description(),
         )
+        .with_locations(file.error_location())
     }
 
     /// Rust file failed to parse (used when scanning for `dioxus_i18n` macros).
@@ -121,6 +385,7 @@ impl AuditIssue {
             Subject::FluentFile(file.path().to_path_buf()),
             file.error_description(),
         )
+        .with_locations(&[file.error_location()])
     }
 
     /// Required locale has no translation files at all.
@@ -184,17 +449,65 @@ impl AuditIssue {
     }
 
     /// Number or names of placeholders differ from canonical.
-    pub fn signature_mismatch(locale: &Locale, identifier: &QualifiedIdentifier) -> Self {
-        Self::new(
+    pub fn signature_mismatch(
+        locale: &Locale,
+        identifier: &QualifiedIdentifier,
+        diff: SignatureDiff,
+    ) -> Self {
+        let mut issue = Self::new(
             Kind::SignatureMismatch,
             Subject::Entry(locale.clone(), identifier.clone()),
             format!("signature mismatch '{}'", identifier.to_meta_string()),
+        );
+        issue.signature_diff = Some(diff);
+        issue
+    }
+
+    /// Entry text is identical to the canonical entry's text.
+    pub fn untranslated_copy(locale: &Locale, identifier: &QualifiedIdentifier) -> Self {
+        Self::new(
+            Kind::UntranslatedCopy,
+            Subject::Entry(locale.clone(), identifier.clone()),
+            format!("untranslated copy '{}'", identifier.to_meta_string()),
+        )
+    }
+
+    /// Plural variant key is not a category of the locale's plural rules.
+    pub fn impossible_plural_category(
+        locale: &Locale,
+        identifier: &QualifiedIdentifier,
+        category: &str,
+    ) -> Self {
+        Self::new(
+            Kind::ImpossiblePluralCategory,
+            Subject::Entry(locale.clone(), identifier.clone()),
+            format!(
+                "impossible plural category '{category}' in '{}'",
+                identifier.to_meta_string()
+            ),
+        )
+    }
+
+    /// Plural category of the locale's plural rules has no variant key.
+    pub fn missing_plural_category(
+        locale: &Locale,
+        identifier: &QualifiedIdentifier,
+        category: &str,
+    ) -> Self {
+        Self::new(
+            Kind::MissingPluralCategory,
+            Subject::Entry(locale.clone(), identifier.clone()),
+            format!(
+                "missing plural category '{category}' in '{}'",
+                identifier.to_meta_string()
+            ),
         )
     }
 
     /// String literal in `t!`/`te!`/`tid!` refers to non-existent key in canonical.
     pub fn undefined_identifier_literal(
         path: &ParsedRustFile,
+        call: &MacroCall,
         identifier: &QualifiedIdentifier,
     ) -> Self {
         Self::new(
@@ -205,15 +518,130 @@ impl AuditIssue {
                 identifier.to_meta_string()
             ),
         )
+        .with_locations(&[path.location(call)])
+    }
+
+    /// Identifier passed to a translation call, through a constant or any other expression,
+    /// cannot be resolved to a string literal.
+    pub fn unverifiable_identifier(path: &ParsedRustFile, call: &MacroCall) -> Self {
+        Self::new(
+            Kind::UnverifiableIdentifier,
+            Subject::RustFile(path.path().to_path_buf()),
+            format!(
+                "identifier {} passed to {} cannot be resolved to a literal",
+                call.constant().or(call.expression()).unwrap_or_default(),
+                call.macro_name()
+            ),
+        )
+        .with_locations(&[path.location(call)])
+    }
+
+    /// Identifier pattern of a `format!` call matches no key in canonical.
+    pub fn unmatched_identifier_pattern(path: &ParsedRustFile, call: &MacroCall) -> Self {
+        Self::new(
+            Kind::UnmatchedIdentifierPattern,
+            Subject::RustFile(path.path().to_path_buf()),
+            format!(
+                "identifier pattern {} matches no identifier in the canonical document",
+                call.pattern().map(|p| p.to_string()).unwrap_or_default()
+            ),
+        )
+        .with_locations(&[path.location(call)])
+    }
+
+    /// Canonical message uses a variable that the `t!`/`te!`/`tid!` call does not pass.
+    pub fn missing_argument(
+        path: &ParsedRustFile,
+        call: &MacroCall,
+        identifier: &QualifiedIdentifier,
+        argument: &str,
+    ) -> Self {
+        Self::new(
+            Kind::ArgumentMismatch,
+            Subject::RustFile(path.path().to_path_buf()),
+            format!(
+                "missing argument '{argument}' for '{}'",
+                identifier.to_meta_string()
+            ),
+        )
+        .with_locations(&[path.location(call)])
+    }
+
+    /// `t!`/`te!`/`tid!` call passes an argument that the canonical message does not use.
+    pub fn unexpected_argument(
+        path: &ParsedRustFile,
+        call: &MacroCall,
+        identifier: &QualifiedIdentifier,
+        argument: &str,
+    ) -> Self {
+        Self::new(
+            Kind::ArgumentMismatch,
+            Subject::RustFile(path.path().to_path_buf()),
+            format!(
+                "unexpected argument '{argument}' for '{}'",
+                identifier.to_meta_string()
+            ),
+        )
+        .with_locations(&[path.location(call)])
+    }
+
+    /// String literal used as text, or a user-visible attribute's value, in `rsx!`.
+    pub fn hardcoded_text(path: &ParsedRustFile, text: &HardcodedText) -> Self {
+        let message = match text.attribute() {
+            Some(attribute) => format!("hardcoded text '{}' in {attribute} attribute", text.text()),
+            None => format!("hardcoded text '{}'", text.text()),
+        };
+
+        Self::new(
+            Kind::HardcodedText,
+            Subject::RustFile(path.path().to_path_buf()),
+            message,
+        )
+        .with_locations(&[path.text_location(text)])
+    }
+
+    /// Canonical message or attribute is not used by the Rust sources or other messages.
+    pub fn unused_identifier(locale: &Locale, identifier: &QualifiedIdentifier) -> Self {
+        Self::new(
+            Kind::UnusedIdentifier,
+            Subject::Entry(locale.clone(), identifier.clone()),
+            format!("unused identifier '{}'", identifier.to_meta_string()),
+        )
+    }
+
+    /// Suppression directive, or one of the kinds it names, silenced no issue.
+    pub fn unused_suppression(directive: &SourceLocation, kind: Option<&str>) -> Self {
+        let path = directive.path().to_path_buf();
+        let subject = if path.extension().is_some_and(|ext| ext == "rs") {
+            Subject::RustFile(path)
+        } else {
+            Subject::FluentFile(path)
+        };
+
+        let message = match kind {
+            Some(kind) if kind.replace('-', "_").parse::<Kind>().is_err() => {
+                format!("unused suppression of unknown kind '{kind}'")
+            }
+            Some(kind) => format!("unused suppression '{kind}'"),
+            None => String::from("unused suppression"),
+        };
+
+        Self::new(Kind::UnusedSuppression, subject, message)
+            .with_locations(std::iter::once(directive))
     }
 
     /// String literal in Rust macro is not a valid Fluent identifier.
-    pub fn malformed_identifier_literal(path: &ParsedRustFile, error: &str) -> Self {
+    pub fn malformed_identifier_literal(
+        path: &ParsedRustFile,
+        call: &MacroCall,
+        error: &str,
+    ) -> Self {
         Self::new(
             Kind::MalformedIdentifierLiteral,
             Subject::RustFile(path.path().to_path_buf()),
             format!("malformed identifier literal: {error}"),
         )
+        .with_locations(&[path.location(call)])
     }
 }
 
@@ -244,6 +672,55 @@ impl AuditIssue {
     pub fn kind(&self) -> &Kind {
         &self.kind
     }
+
+    /// The severity of this issue; only `Severity::Error` issues fail an audit.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The source locations where this issue can be seen, most relevant first.
+    ///
+    /// Empty when the issue has no single place in the sources, e.g. a missing locale.
+    pub fn locations(&self) -> &[SourceLocation] {
+        &self.locations
+    }
+
+    /// How the entry's signature differs from its base, if this is a signature mismatch.
+    pub fn signature_diff(&self) -> Option<&SignatureDiff> {
+        self.signature_diff.as_ref()
+    }
+
+    /// The identifiers that were possibly intended, nearest first, e.g. `login-button` for
+    /// an undefined `login-buton`.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// A hint listing the suggestions, if any, e.g. `did you mean 'a', 'b' or 'c'?`.
+    pub fn help(&self) -> Option<String> {
+        let quoted = Vec::from_iter(self.suggestions.iter().map(|s| format!("'{s}'")));
+
+        match quoted.split_last() {
+            None => None,
+            Some((last, [])) => Some(format!("did you mean {last}?")),
+            Some((last, rest)) => Some(format!("did you mean {} or {last}?", rest.join(", "))),
+        }
+    }
+
+    /// A stable identity for this issue, e.g. for recording it in a `Baseline`.
+    ///
+    /// Formed from the kind, subject and message (which names the identifier concerned),
+    /// but not the locations, so that it is unchanged when unrelated edits move the
+    /// issue within its file. The message of a parse error describes where parsing
+    /// failed, so is left out of its fingerprint. Paths use `/` separators, whatever
+    /// the platform.
+    pub fn fingerprint(&self) -> String {
+        let subject = self.subject.to_string().replace('\\', "/");
+        match self.kind {
+            Kind::ParseError => format!("{}|{subject}", self.kind.id()),
+            _ => format!("{}|{subject}|{}", self.kind.id(), self.message),
+        }
+    }
 }
 
 impl std::fmt::Display for AuditIssue {
@@ -251,3 +728,137 @@ impl std::fmt::Display for AuditIssue {
         self.message.fmt(f)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_support::{identifier, locale, root};
+
+    #[test]
+    fn kinds_will_parse_from_their_ids_and_aliases() {
+        assert_eq!(
+            "undefined_identifier_literal".parse::<Kind>().ok(),
+            Some(Kind::UndefinedIdentifierLiteral)
+        );
+        assert_eq!(
+            "undefined_identifier".parse::<Kind>().ok(),
+            Some(Kind::UndefinedIdentifierLiteral)
+        );
+        assert_eq!(
+            "malformed_identifier".parse::<Kind>().ok(),
+            Some(Kind::MalformedIdentifierLiteral)
+        );
+        assert!("identifier".parse::<Kind>().is_err());
+    }
+
+    #[test]
+    fn help_will_list_the_suggestions() {
+        let issue = AuditIssue::invalid_reference(&locale("en-GB"), &identifier("-brnad"));
+        assert_eq!(issue.help(), None);
+
+        let issue = issue.with_suggestions(vec!["-brand".into()]);
+        assert_eq!(issue.help().as_deref(), Some("did you mean '-brand'?"));
+
+        let issue = issue.with_suggestions(vec!["-brand".into(), "-bran".into(), "-band".into()]);
+        assert_eq!(
+            issue.help().as_deref(),
+            Some("did you mean '-brand', '-bran' or '-band'?")
+        );
+    }
+
+    #[test]
+    fn will_serialize_entry_issue() {
+        let location = SourceLocation::new(Path::new("i18n/fr-FR.ftl"), 2, 5);
+        let issue = AuditIssue::redundant_translation(&locale("fr-FR"), &identifier("greeting"))
+            .with_locations(&[location]);
+
+        let value = serde_json::to_value(&issue).expect("serializable issue");
+
+        assert_eq!(
+            value,
+            json!({
+                "kind": "redundant_translation",
+                "severity": "error",
+                "subject": { "type": "entry", "locale": "fr-FR", "identifier": "greeting" },
+                "message": "redundant translation 'greeting'",
+                "locations": [{ "path": "i18n/fr-FR.ftl", "line": 2, "column": 5 }],
+            })
+        );
+    }
+
+    #[test]
+    fn will_serialize_signature_diff() {
+        let issue = AuditIssue::signature_mismatch(
+            &locale("fr-FR"),
+            &identifier("greeting"),
+            SignatureDiff::default(),
+        );
+
+        let value = serde_json::to_value(&issue).expect("serializable issue");
+
+        assert_eq!(value["signature_diff"], json!({}));
+    }
+
+    #[test]
+    fn fingerprints_ignore_locations() {
+        let issue = AuditIssue::redundant_translation(&locale("fr-FR"), &identifier("greeting"));
+        let moved =
+            issue
+                .clone()
+                .with_locations(&[SourceLocation::new(Path::new("fr-FR.ftl"), 9, 1)]);
+
+        assert_eq!(
+            issue.fingerprint(),
+            "redundant_translation|fr-FR :: greeting|redundant translation 'greeting'"
+        );
+        assert_eq!(issue.fingerprint(), moved.fingerprint());
+    }
+
+    #[test]
+    fn fingerprints_of_parse_errors_ignore_where_parsing_failed() {
+        let error_at = |line: usize, column: usize| {
+            AuditIssue::new(
+                Kind::ParseError,
+                Subject::FluentFile(PathBuf::from("i18n/fr-FR.ftl")),
+                format!("expected '=' at {line}:{column}"),
+            )
+            .with_locations(&[SourceLocation::new(
+                Path::new("i18n/fr-FR.ftl"),
+                line,
+                column,
+            )])
+        };
+
+        assert_eq!(error_at(3, 7).fingerprint(), "parse_error|i18n/fr-FR.ftl");
+        assert_eq!(error_at(3, 7).fingerprint(), error_at(12, 1).fingerprint());
+    }
+
+    #[test]
+    fn will_serialize_subjects_with_type_tag() {
+        let subjects = [
+            Subject::FluentFile(PathBuf::from("en.ftl")),
+            Subject::RustFile(PathBuf::from("main.rs")),
+            Subject::Locale(locale("en-GB")),
+            Subject::LanguageRoot(root("sr-Cyrl-RS")),
+        ];
+
+        let values = subjects
+            .iter()
+            .map(|s| serde_json::to_value(s).expect("serializable subject"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            values,
+            [
+                json!({ "type": "fluent_file", "path": "en.ftl" }),
+                json!({ "type": "rust_file", "path": "main.rs" }),
+                json!({ "type": "locale", "locale": "en-GB" }),
+                json!({ "type": "language_root", "language_root": "sr-Cyrl" }),
+            ]
+        );
+    }
+}
@@ -1,11 +1,19 @@
+mod baseline;
 mod engine;
 mod issue;
+mod parse_cache;
 mod pipeline;
 mod result;
+mod suggestion;
+mod suppression;
 mod workspace;
 
+pub use baseline::Baseline;
 pub use engine::AuditEngine;
 pub use issue::{AuditIssue, Kind, Subject};
+pub use parse_cache::ParseCache;
 pub use pipeline::Pipeline;
 pub use result::{AuditResult, AuditedDocument, DocumentRole};
+pub(crate) use suggestion::suggestions;
+pub(crate) use suppression::{Suppression, apply_suppressions};
 pub use workspace::Workspace;
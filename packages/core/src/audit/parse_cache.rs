@@ -0,0 +1,515 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    audit::pipeline::locales,
+    config::RustScanSettings,
+    domain::Locale,
+    error::LingoraError,
+    fluent::{FluentDocument, FluentFile, FluentFileContent, ParsedFluentFile},
+    parallel,
+    rust::{ParsedRustFile, RustFile, RustFileContent},
+};
+
+/// The size, modification time and content hash of a file when it was last parsed.
+///
+/// A file whose size and modification time are unchanged is taken to be unchanged, without
+/// being read; otherwise it is read, and is unchanged if its content hash is.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Fingerprint {
+    size: u64,
+    modified: Option<SystemTime>,
+    hash: String,
+}
+
+impl Fingerprint {
+    fn new(metadata: &fs::Metadata, content: &str) -> Self {
+        Self {
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+            hash: format!("{:x}", Sha256::digest(content)),
+        }
+    }
+
+    fn matches(&self, metadata: &fs::Metadata) -> bool {
+        let modified = metadata.modified().ok();
+        self.size == metadata.len() && modified.is_some() && self.modified == modified
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Entry<T> {
+    fingerprint: Fingerprint,
+    content: T,
+}
+
+/// The outcome of looking a file up in the cache; either its cached content, perhaps with
+/// a refreshed fingerprint, or its changed content, which must be parsed.
+enum Lookup<T> {
+    Unchanged(Entry<T>),
+    Changed(Fingerprint, String),
+}
+
+impl<T: Clone> Lookup<T> {
+    fn new(entries: &BTreeMap<PathBuf, Entry<T>>, path: &Path) -> Result<Self, LingoraError> {
+        let metadata = fs::metadata(path)?;
+        let entry = entries.get(path);
+
+        if let Some(entry) = entry.filter(|entry| entry.fingerprint.matches(&metadata)) {
+            return Ok(Self::Unchanged(entry.clone()));
+        }
+
+        let source = fs::read_to_string(path)?;
+        let fingerprint = Fingerprint::new(&metadata, &source);
+
+        match entry.filter(|entry| entry.fingerprint.hash == fingerprint.hash) {
+            Some(entry) => Ok(Self::Unchanged(Entry {
+                fingerprint,
+                content: entry.content.clone(),
+            })),
+            None => Ok(Self::Changed(fingerprint, source)),
+        }
+    }
+}
+
+/// The documents built in this process, by locale, each with the paths and content hashes
+/// of the files it was built from.
+type Documents = HashMap<Locale, (Vec<(PathBuf, String)>, FluentDocument)>;
+
+/// A cache of what was found by parsing each Fluent and Rust file, keyed by the file's path,
+/// size, modification time and content hash, so that an audit need only parse the files
+/// that have changed since the previous one.
+///
+/// The cache is written to a file, e.g. the `cache` given in `Lingora.toml`, as JSON, and
+/// is discarded when written by another version of lingora. Files that failed to parse
+/// are not cached, and so are parsed on each audit, reporting the failure again.
+///
+/// Within a process, e.g. when re-auditing in watch mode, the cache also keeps the
+/// documents built for each locale, along with their analysis, so that only the
+/// documents of the locales whose files have changed are rebuilt.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ParseCache {
+    schema_version: u32,
+    lingora_version: String,
+    rust_scan: RustScanSettings,
+    hardcoded_text: bool,
+    fluent_files: BTreeMap<PathBuf, Entry<FluentFileContent>>,
+    rust_files: BTreeMap<PathBuf, Entry<RustFileContent>>,
+    #[serde(skip)]
+    documents: Documents,
+    #[serde(skip)]
+    parsed: Vec<PathBuf>,
+    #[serde(skip)]
+    built: Vec<Locale>,
+}
+
+impl ParseCache {
+    /// The version of the cache file format.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Reads a cache previously written by `ParseCache::save`.
+    ///
+    /// A missing or unreadable cache, or one written by another version of lingora, is
+    /// treated as empty, so that every file is parsed and the cache rewritten.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|cache| {
+                cache.schema_version == Self::SCHEMA_VERSION
+                    && cache.lingora_version == env!("CARGO_PKG_VERSION")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache to the given path, creating its folder if needed, and replacing
+    /// any existing file.
+    pub fn save(&self, path: &Path) -> Result<(), LingoraError> {
+        if let Some(folder) = path
+            .parent()
+            .filter(|folder| !folder.as_os_str().is_empty())
+        {
+            fs::create_dir_all(folder)?;
+        }
+
+        let cache = Self {
+            schema_version: Self::SCHEMA_VERSION,
+            lingora_version: env!("CARGO_PKG_VERSION").into(),
+            rust_scan: self.rust_scan.clone(),
+            hardcoded_text: self.hardcoded_text,
+            fluent_files: self.fluent_files.clone(),
+            rust_files: self.rust_files.clone(),
+            ..Self::default()
+        };
+        fs::write(path, serde_json::to_string(&cache)?)?;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the cache holds no files, e.g. before the first audit.
+    pub fn is_empty(&self) -> bool {
+        self.fluent_files.is_empty() && self.rust_files.is_empty()
+    }
+
+    /// Parses the Fluent and Rust files that have changed since they were cached, and
+    /// restores the others from the cache; files no longer audited are dropped from the
+    /// cache, as are all Rust files if what they are scanned for, i.e. the settings or
+    /// whether hardcoded text is collected, has changed.
+    pub(crate) fn parse_files(
+        &mut self,
+        fluent_files: &[FluentFile],
+        rust_files: &[RustFile],
+        settings: &RustScanSettings,
+        hardcoded_text: bool,
+    ) -> Result<(Vec<ParsedFluentFile>, Vec<ParsedRustFile>), LingoraError> {
+        if self.rust_scan != *settings || self.hardcoded_text != hardcoded_text {
+            self.rust_scan = settings.clone();
+            self.hardcoded_text = hardcoded_text;
+            self.rust_files.clear();
+        }
+
+        self.parsed.clear();
+
+        let fluent_files = parse_all(
+            &mut self.fluent_files,
+            &mut self.parsed,
+            fluent_files,
+            FluentFile::path,
+            ParsedFluentFile::parse_source,
+            ParsedFluentFile::from_content,
+            ParsedFluentFile::content,
+        )?;
+
+        let rust_files = parse_all(
+            &mut self.rust_files,
+            &mut self.parsed,
+            rust_files,
+            RustFile::path,
+            |file, source| ParsedRustFile::parse_source(file, source, settings, hardcoded_text),
+            ParsedRustFile::from_content,
+            ParsedRustFile::content,
+        )?;
+
+        Ok((fluent_files, rust_files))
+    }
+
+    /// Returns the document of each locale, as previously built if none of its files have
+    /// changed, or otherwise built from the parsed files.
+    pub(crate) fn collect_documents(&mut self, files: &[ParsedFluentFile]) -> Vec<FluentDocument> {
+        let documents = parallel::map(&locales(files), |locale| {
+            let paths = files
+                .iter()
+                .filter(|file| file.locale() == *locale)
+                .map(ParsedFluentFile::path);
+
+            match (self.document_key(paths), self.documents.get(locale)) {
+                (Some(key), Some((built_from, document))) if key == *built_from => {
+                    (document.clone(), false)
+                }
+                _ => (FluentDocument::from_parsed_files(locale, files), true),
+            }
+        });
+
+        self.built = Vec::from_iter(
+            documents
+                .iter()
+                .filter(|(_, built)| *built)
+                .map(|(document, _)| document.locale().clone()),
+        );
+
+        let documents = Vec::from_iter(documents.into_iter().map(|(document, _)| document));
+        self.retain_documents(&documents);
+        documents
+    }
+
+    /// Keeps the documents, e.g. once audited so that their analysis is kept too, for the
+    /// next audit; those built from a file that failed to parse are not kept.
+    pub(crate) fn retain_documents<'a>(
+        &mut self,
+        documents: impl IntoIterator<Item = &'a FluentDocument>,
+    ) {
+        self.documents = documents
+            .into_iter()
+            .filter_map(|document| {
+                let key = self.document_key(document.paths())?;
+                Some((document.locale().clone(), (key, document.clone())))
+            })
+            .collect();
+    }
+
+    /// The files parsed, rather than restored from the cache, by the most recent audit.
+    #[cfg(test)]
+    pub(crate) fn parsed(&self) -> &[PathBuf] {
+        &self.parsed
+    }
+
+    /// The locales whose documents were built, rather than reused, by the most recent
+    /// audit, sorted.
+    #[cfg(test)]
+    pub(crate) fn built(&self) -> &[Locale] {
+        &self.built
+    }
+
+    fn document_key<'a>(
+        &self,
+        paths: impl Iterator<Item = &'a Path>,
+    ) -> Option<Vec<(PathBuf, String)>> {
+        let mut key = paths
+            .map(|path| {
+                let entry = self.fluent_files.get(path)?;
+                Some((path.to_path_buf(), entry.fingerprint.hash.clone()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        key.sort();
+        Some(key)
+    }
+}
+
+/// Parses, or restores from the cached `entries`, each of the `files`, replacing the
+/// entries with those of the files, and recording the paths of those parsed.
+fn parse_all<F, P, C>(
+    entries: &mut BTreeMap<PathBuf, Entry<C>>,
+    parsed: &mut Vec<PathBuf>,
+    files: &[F],
+    path: impl Fn(&F) -> &Path + Sync,
+    parse: impl Fn(&F, &str) -> P + Sync,
+    restore: impl Fn(&F, C) -> P + Sync,
+    content: impl Fn(&P) -> Option<C> + Sync,
+) -> Result<Vec<P>, LingoraError>
+where
+    F: Sync,
+    P: Send,
+    C: Clone + Send + Sync,
+{
+    let results = parallel::map(files, |file| match Lookup::new(entries, path(file))? {
+        Lookup::Unchanged(entry) => {
+            let restored = restore(file, entry.content.clone());
+            Ok((restored, Some(entry), false))
+        }
+        Lookup::Changed(fingerprint, source) => {
+            let parsed = parse(file, &source);
+            let entry = content(&parsed).map(|content| Entry {
+                fingerprint,
+                content,
+            });
+            Ok((parsed, entry, true))
+        }
+    })
+    .into_iter()
+    .collect::<Result<Vec<_>, LingoraError>>()?;
+
+    entries.clear();
+
+    Ok(files
+        .iter()
+        .zip(results)
+        .map(|(file, (file_parsed, entry, was_parsed))| {
+            let path = path(file).to_path_buf();
+            if was_parsed {
+                parsed.push(path.clone());
+            }
+            if let Some(entry) = entry {
+                entries.insert(path, entry);
+            }
+            file_parsed
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{str::FromStr, time::Duration};
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{
+        audit::{AuditEngine, AuditIssue},
+        config::LingoraToml,
+        test_support::locale,
+    };
+
+    struct Sources {
+        root: TempDir,
+        engine: AuditEngine,
+    }
+
+    impl Sources {
+        fn new() -> Self {
+            let root = TempDir::new().expect("temp dir");
+            let i18n = root.path().join("i18n");
+            fs::create_dir_all(&i18n).expect("i18n dir");
+
+            fs::write(
+                i18n.join("en-GB.ftl"),
+                "greeting = Hello\nfarewell = Goodbye\n",
+            )
+            .expect("en-GB file");
+            fs::write(i18n.join("fr-FR.ftl"), "greeting = Bonjour\n").expect("fr-FR file");
+            fs::write(i18n.join("it-IT.ftl"), "greeting = Ciao\n").expect("it-IT file");
+            fs::write(
+                root.path().join("app.rs"),
+                r#"fn app() { t!("greeting"); }"#,
+            )
+            .expect("rust file");
+
+            let toml = format!(
+                r#"
+[lingora]
+fluent_sources = [{:?}]
+canonical = "en-GB"
+primaries = ["fr-FR", "it-IT"]
+cache = {:?}
+
+[dioxus_i18n]
+rust_sources = [{:?}]
+"#,
+                i18n.display().to_string(),
+                root.path().join("cache/lingora.json").display().to_string(),
+                root.path().join("app.rs").display().to_string()
+            );
+            let settings = LingoraToml::from_str(&toml).expect("valid settings");
+            let engine = AuditEngine::try_from(&settings).expect("valid engine");
+
+            Self { root, engine }
+        }
+
+        fn path(&self, path: &str) -> PathBuf {
+            self.root.path().join(path)
+        }
+
+        fn cache_file(&self) -> PathBuf {
+            self.path("cache/lingora.json")
+        }
+
+        fn issues(&self, cache: &mut ParseCache) -> Vec<String> {
+            let result = self.engine.run_with_cache(cache).expect("audit");
+            let mut issues = Vec::from_iter(result.issues().map(AuditIssue::fingerprint));
+            issues.sort();
+            issues
+        }
+    }
+
+    #[test]
+    fn unchanged_files_will_not_be_parsed_again() {
+        let sources = Sources::new();
+        let mut cache = ParseCache::default();
+
+        let issues = sources.issues(&mut cache);
+        assert_eq!(cache.parsed().len(), 4);
+        assert_eq!(
+            cache.built(),
+            [locale("en-GB"), locale("fr-FR"), locale("it-IT")]
+        );
+
+        assert_eq!(sources.issues(&mut cache), issues);
+        assert!(cache.parsed().is_empty());
+        assert!(cache.built().is_empty());
+    }
+
+    #[test]
+    fn only_the_documents_of_changed_files_will_be_rebuilt() {
+        let sources = Sources::new();
+        let mut cache = ParseCache::default();
+        sources.issues(&mut cache);
+
+        fs::write(
+            sources.path("i18n/fr-FR.ftl"),
+            "greeting = Bonjour\nfarewell = Au revoir\n",
+        )
+        .expect("fr-FR file");
+        let issues = sources.issues(&mut cache);
+
+        assert_eq!(cache.parsed(), [sources.path("i18n/fr-FR.ftl")]);
+        assert_eq!(cache.built(), [locale("fr-FR")]);
+        assert_eq!(issues, sources.issues(&mut ParseCache::default()));
+        assert!(
+            issues
+                .iter()
+                .any(|issue| issue.starts_with("missing_translation|it-IT"))
+        );
+        assert!(
+            !issues
+                .iter()
+                .any(|issue| issue.starts_with("missing_translation|fr-FR"))
+        );
+    }
+
+    #[test]
+    fn files_touched_without_change_will_not_be_parsed_again() {
+        let sources = Sources::new();
+        let mut cache = ParseCache::default();
+        sources.issues(&mut cache);
+
+        let path = sources.path("app.rs");
+        let modified = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .expect("mtime");
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(modified + Duration::from_secs(10)))
+            .expect("touched");
+        sources.issues(&mut cache);
+
+        assert!(cache.parsed().is_empty());
+    }
+
+    #[test]
+    fn the_cache_will_be_restored_from_its_file() {
+        let sources = Sources::new();
+        let issues = sources.issues(&mut ParseCache::default());
+        assert!(sources.cache_file().exists());
+
+        let mut cache = ParseCache::load(&sources.cache_file());
+        assert!(!cache.is_empty());
+        assert_eq!(sources.issues(&mut cache), issues);
+        assert!(cache.parsed().is_empty());
+
+        let mut cache = ParseCache::default();
+        assert_eq!(sources.issues(&mut cache), issues);
+        assert!(cache.parsed().is_empty());
+    }
+
+    #[test]
+    fn files_that_fail_to_parse_will_be_parsed_again() {
+        let sources = Sources::new();
+        fs::write(sources.path("i18n/it-IT.ftl"), "greeting = {\n").expect("it-IT file");
+        fs::write(sources.path("app.rs"), "fn app() {").expect("rust file");
+        let mut cache = ParseCache::default();
+        let issues = sources.issues(&mut cache);
+
+        assert_eq!(sources.issues(&mut cache), issues);
+        assert_eq!(
+            cache.parsed(),
+            [sources.path("i18n/it-IT.ftl"), sources.path("app.rs")]
+        );
+        assert_eq!(cache.built(), [locale("it-IT")]);
+    }
+
+    #[test]
+    fn caches_written_by_other_versions_will_be_discarded() {
+        let sources = Sources::new();
+        sources.issues(&mut ParseCache::default());
+
+        let content = fs::read_to_string(sources.cache_file()).expect("cache file");
+        let content = content.replace(
+            &format!(r#""lingora_version":"{}""#, env!("CARGO_PKG_VERSION")),
+            r#""lingora_version":"0.0.0""#,
+        );
+        fs::write(sources.cache_file(), content).expect("cache file");
+
+        let mut cache = ParseCache::load(&sources.cache_file());
+        sources.issues(&mut cache);
+
+        assert_eq!(cache.parsed().len(), 4);
+    }
+}
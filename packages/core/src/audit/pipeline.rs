@@ -3,20 +3,29 @@ use std::{
     marker::PhantomData,
 };
 
-use rayon::prelude::*;
+use fluent4rs::ast::{Attribute, Entry, Pattern};
 
 use crate::{
     audit::{
-        AuditIssue, AuditResult, Workspace,
+        AuditIssue, AuditResult, ParseCache, Workspace, apply_suppressions,
         result::{AuditedDocument, DocumentRole},
+        suggestions,
     },
-    domain::{HasLocale, LanguageRoot, Locale},
+    config::{AuditSettings, RustScanSettings, Severity, SeveritySettings},
+    domain::{HasLocale, LanguageRoot, Locale, SourceLocation},
     error::LingoraError,
-    fluent::{FluentDocument, FluentFile, ParsedFluentFile, QualifiedIdentifier},
-    rust::{ParsedRustFile, RustFile},
+    fluent::{FluentDocument, FluentFile, ParsedFluentFile, QualifiedIdentifier, SignatureDiff},
+    parallel,
+    rust::{MacroCall, ParsedRustFile, RustFile},
 };
 
-pub(super) struct Empty;
+/// An entry preceded by this comment, in either the canonical or primary document, may
+/// legitimately be identical in both.
+const ALLOW_COPY_MARKER: &str = "lingora-allow-copy";
+
+pub(super) struct Empty {
+    rust_scan: RustScanSettings,
+}
 
 pub(super) struct ParsedFiles {
     fluent_files: Vec<ParsedFluentFile>,
@@ -41,39 +50,80 @@ pub(super) struct Audited {
     primaries: Vec<FluentDocument>,
     variants: Vec<FluentDocument>,
     orphans: Vec<FluentDocument>,
+    macro_calls: Vec<(SourceLocation, MacroCall)>,
 }
 
 pub struct Pipeline<S> {
     state: S,
     issues: Vec<AuditIssue>,
+    settings: AuditSettings,
+    severity: SeveritySettings,
+    cache: Option<ParseCache>,
     _state: PhantomData<S>,
 }
 
 impl Default for Pipeline<Empty> {
     fn default() -> Self {
+        Self::with_settings(&AuditSettings::default())
+    }
+}
+
+impl Pipeline<Empty> {
+    pub fn with_settings(settings: &AuditSettings) -> Self {
         Self {
-            state: Empty,
+            state: Empty {
+                rust_scan: RustScanSettings::default(),
+            },
             issues: Vec::default(),
+            settings: settings.clone(),
+            severity: SeveritySettings::default(),
+            cache: None,
             _state: Default::default(),
         }
     }
-}
 
-impl Pipeline<Empty> {
+    pub fn with_severity(mut self, severity: &SeveritySettings) -> Self {
+        self.severity = severity.clone();
+        self
+    }
+
+    pub fn with_rust_scan(mut self, rust_scan: &RustScanSettings) -> Self {
+        self.state.rust_scan = rust_scan.clone();
+        self
+    }
+
+    /// Parses only the files changed since they were cached, and rebuilds only the
+    /// documents of the locales whose files changed; the cache, updated, is returned
+    /// by `Pipeline::take_cache` once audited.
+    pub fn with_cache(mut self, cache: ParseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     pub fn parse_files(
         mut self,
         fluent_files: &[FluentFile],
         rust_files: &[RustFile],
     ) -> Result<Pipeline<ParsedFiles>, LingoraError> {
-        let fluent_files = fluent_files
-            .par_iter()
-            .map(ParsedFluentFile::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let rust_files = rust_files
-            .par_iter()
-            .map(ParsedRustFile::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
+        let (fluent_files, mut rust_files) = match &mut self.cache {
+            Some(cache) => cache.parse_files(
+                fluent_files,
+                rust_files,
+                &self.state.rust_scan,
+                self.settings.hardcoded_text,
+            )?,
+            None => (
+                parallel::map(fluent_files, |file| ParsedFluentFile::try_from(file))
+                    .into_iter()
+                    .collect::<Result<Vec<_>, _>>()?,
+                parallel::map(rust_files, |file| {
+                    ParsedRustFile::parse(file, &self.state.rust_scan, self.settings.hardcoded_text)
+                })
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+        ParsedRustFile::resolve_constants(&mut rust_files);
 
         self.emit_parse_fluent_file_errors(&fluent_files);
         self.emit_parse_rust_file_errors(&rust_files);
@@ -86,6 +136,9 @@ impl Pipeline<Empty> {
         Ok(Pipeline::<_> {
             state,
             issues: self.issues,
+            settings: self.settings,
+            severity: self.severity,
+            cache: self.cache,
             _state: Default::default(),
         })
     }
@@ -106,18 +159,16 @@ impl Pipeline<Empty> {
 }
 
 impl Pipeline<ParsedFiles> {
-    pub fn collect_documents_by_locale(self) -> Pipeline<DocumentsCollected> {
-        let locales = self
-            .state
-            .fluent_files
-            .iter()
-            .map(|f| f.locale())
-            .collect::<HashSet<_>>();
-
-        let documents = locales
-            .into_par_iter()
-            .map(|locale| FluentDocument::from_parsed_files(locale, &self.state.fluent_files))
-            .collect();
+    pub fn collect_documents_by_locale(mut self) -> Pipeline<DocumentsCollected> {
+        let documents = match &mut self.cache {
+            Some(cache) => cache.collect_documents(&self.state.fluent_files),
+            None => {
+                let files = &self.state.fluent_files;
+                parallel::map(&locales(files), |locale| {
+                    FluentDocument::from_parsed_files(locale, files)
+                })
+            }
+        };
 
         let state = DocumentsCollected {
             documents,
@@ -127,11 +178,22 @@ impl Pipeline<ParsedFiles> {
         Pipeline::<_> {
             state,
             issues: self.issues,
+            settings: self.settings,
+            severity: self.severity,
+            cache: self.cache,
             _state: Default::default(),
         }
     }
 }
 
+/// The locales of the files, once each, in order, so that the documents built for them
+/// are ordered too.
+pub(super) fn locales(files: &[ParsedFluentFile]) -> Vec<&Locale> {
+    let mut locales = Vec::from_iter(files.iter().map(|f| f.locale()).collect::<HashSet<_>>());
+    locales.sort_by_key(|locale| locale.to_string());
+    locales
+}
+
 impl Pipeline<DocumentsCollected> {
     pub fn classify_documents(
         mut self,
@@ -189,24 +251,30 @@ impl Pipeline<DocumentsCollected> {
         Pipeline::<_> {
             state,
             issues: self.issues,
+            settings: self.settings,
+            severity: self.severity,
+            cache: self.cache,
             _state: Default::default(),
         }
     }
 
     fn emit_undefined_bases(&mut self, orphans: &[FluentDocument]) {
-        let locales_by_root =
-            orphans
-                .iter()
-                .map(|orphan| orphan.locale())
-                .fold(HashMap::new(), |mut acc, locale| {
-                    let root = LanguageRoot::from(locale);
-                    acc.entry(root).or_insert(Vec::new()).push(locale.clone());
-                    acc
-                });
+        let orphans_by_root = orphans.iter().fold(HashMap::new(), |mut acc, orphan| {
+            let root = LanguageRoot::from(orphan.locale());
+            acc.entry(root).or_insert(Vec::new()).push(orphan);
+            acc
+        });
 
-        locales_by_root.iter().for_each(|(root, locales)| {
+        orphans_by_root.iter().for_each(|(root, orphans)| {
+            let locales = Vec::from_iter(orphans.iter().map(|orphan| orphan.locale().clone()));
+            let locations = Vec::from_iter(
+                orphans
+                    .iter()
+                    .flat_map(|orphan| orphan.paths())
+                    .map(SourceLocation::start_of),
+            );
             self.issues
-                .push(AuditIssue::undefined_base_locale(root, locales));
+                .push(AuditIssue::undefined_base_locale(root, &locales).with_locations(&locations));
         });
     }
 
@@ -229,26 +297,59 @@ impl Pipeline<DocumentsCollected> {
 
 impl Pipeline<DocumentsClassified> {
     pub fn audit(mut self) -> Pipeline<Audited> {
+        self.analyse_documents();
         self.emit_duplicate_identifiers();
         self.emit_invalid_references();
+        self.emit_plural_category_issues();
         self.emit_canonical_to_primary_issues();
         self.emit_base_to_variant_issues();
         self.emit_rust_file_to_canonical_issues();
+        self.emit_hardcoded_text_issues();
+        self.emit_unused_identifiers();
+        self.apply_suppressions();
+        self.apply_severity();
+        self.sort_issues();
+
+        let mut rust_files = Vec::from_iter(self.state.rust_files.iter());
+        rust_files.sort_by(|a, b| a.path().cmp(b.path()));
+        let macro_calls = Vec::from_iter(rust_files.into_iter().flat_map(|file| {
+            file.macro_calls()
+                .map(|call| (file.location(call), call.clone()))
+        }));
 
         let state = Audited {
             canonical: self.state.canonical,
             primaries: self.state.primaries,
             variants: self.state.variants,
             orphans: self.state.orphans,
+            macro_calls,
         };
 
         Pipeline::<_> {
             state,
             issues: self.issues,
+            settings: self.settings,
+            severity: self.severity,
+            cache: self.cache,
             _state: Default::default(),
         }
     }
 
+    /// Analyses each document up front, rather than as first used by the checks below, so
+    /// that the documents may be analysed in parallel.
+    fn analyse_documents(&self) {
+        let documents = Vec::from_iter(
+            self.state
+                .canonical
+                .iter()
+                .chain(self.state.primaries.iter())
+                .chain(self.state.variants.iter())
+                .chain(self.state.orphans.iter()),
+        );
+
+        parallel::for_each(&documents, |document| document.analyse());
+    }
+
     pub fn emit_duplicate_identifiers(&mut self) {
         self.state
             .canonical
@@ -260,10 +361,10 @@ impl Pipeline<DocumentsClassified> {
                 document
                     .duplicate_identifier_names()
                     .for_each(|identifier| {
-                        self.issues.push(AuditIssue::duplicate_identifier(
-                            document.locale(),
-                            &identifier,
-                        ))
+                        self.issues.push(
+                            AuditIssue::duplicate_identifier(document.locale(), &identifier)
+                                .with_locations(document.locations(&identifier)),
+                        )
                     })
             });
     }
@@ -276,13 +377,67 @@ impl Pipeline<DocumentsClassified> {
             .chain(self.state.variants.iter())
             .chain(self.state.orphans.iter())
             .for_each(|document| {
+                let candidates = Vec::from_iter(
+                    document
+                        .entry_identifiers()
+                        .filter_map(|identifier| identifier.to_literal_string()),
+                );
+
                 document.invalid_references().for_each(|reference| {
-                    self.issues
-                        .push(AuditIssue::invalid_reference(document.locale(), &reference))
+                    let locations =
+                        Vec::from_iter(document.referrers(&reference).flat_map(|referrer| {
+                            Vec::from_iter(document.locations(&referrer).cloned())
+                        }));
+                    let suggestions = reference.to_literal_string().map(|literal| {
+                        suggestions(&literal, candidates.iter().map(String::as_str))
+                    });
+                    self.issues.push(
+                        AuditIssue::invalid_reference(document.locale(), &reference)
+                            .with_locations(&locations)
+                            .with_suggestions(suggestions.unwrap_or_default()),
+                    )
                 })
             });
     }
 
+    pub fn emit_plural_category_issues(&mut self) {
+        self.state
+            .canonical
+            .iter()
+            .chain(self.state.primaries.iter())
+            .chain(self.state.variants.iter())
+            .chain(self.state.orphans.iter())
+            .for_each(|document| {
+                let locale = document.locale();
+                let mut selects = Vec::from_iter(document.plural_selects());
+                selects.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                selects.iter().for_each(|(identifier, select)| {
+                    let locations = Vec::from_iter(document.locations(identifier).cloned());
+                    select
+                        .impossible_categories(locale)
+                        .into_iter()
+                        .for_each(|category| {
+                            self.issues.push(
+                                AuditIssue::impossible_plural_category(
+                                    locale, identifier, category,
+                                )
+                                .with_locations(&locations),
+                            )
+                        });
+                    select
+                        .missing_categories(locale)
+                        .into_iter()
+                        .for_each(|category| {
+                            self.issues.push(
+                                AuditIssue::missing_plural_category(locale, identifier, category)
+                                    .with_locations(&locations),
+                            )
+                        });
+                });
+            });
+    }
+
     pub fn emit_canonical_to_primary_issues(&mut self) {
         if let Some(canonical) = &self.state.canonical {
             let canonical_identifiers = canonical.entry_identifiers().collect::<HashSet<_>>();
@@ -292,29 +447,89 @@ impl Pipeline<DocumentsClassified> {
                 canonical_identifiers
                     .difference(&primary_identifiers)
                     .for_each(|i| {
-                        self.issues
-                            .push(AuditIssue::missing_translation(primary.locale(), i))
+                        self.issues.push(
+                            AuditIssue::missing_translation(primary.locale(), i)
+                                .with_locations(canonical.locations(i)),
+                        )
                     });
 
                 canonical_identifiers
                     .intersection(&primary_identifiers)
                     .for_each(|i| {
-                        if canonical.signature(i) != primary.signature(i) {
-                            self.issues
-                                .push(AuditIssue::signature_mismatch(primary.locale(), i));
+                        let locations = primary.locations(i).chain(canonical.locations(i));
+                        if let Some(diff) = Self::signature_diff(canonical, primary, i) {
+                            self.issues.push(
+                                AuditIssue::signature_mismatch(primary.locale(), i, diff)
+                                    .with_locations(locations),
+                            );
+                        } else if Self::is_untranslated_copy(&self.settings, canonical, primary, i)
+                        {
+                            self.issues.push(
+                                AuditIssue::untranslated_copy(primary.locale(), i)
+                                    .with_locations(locations),
+                            );
                         }
                     });
 
                 primary_identifiers
                     .difference(&canonical_identifiers)
                     .for_each(|i| {
-                        self.issues
-                            .push(AuditIssue::redundant_translation(primary.locale(), i));
+                        self.issues.push(
+                            AuditIssue::redundant_translation(primary.locale(), i)
+                                .with_locations(primary.locations(i)),
+                        );
                     });
             });
         }
     }
 
+    fn signature_diff(
+        base: &FluentDocument,
+        target: &FluentDocument,
+        identifier: &QualifiedIdentifier,
+    ) -> Option<SignatureDiff> {
+        match (base.signature(identifier), target.signature(identifier)) {
+            (Some(base), Some(target)) if base != target => {
+                Some(base.diff(target)).filter(|diff| !diff.is_empty())
+            }
+            (base, target) if base != target => Some(SignatureDiff::default()),
+            _ => None,
+        }
+    }
+
+    fn is_untranslated_copy(
+        settings: &AuditSettings,
+        canonical: &FluentDocument,
+        primary: &FluentDocument,
+        identifier: &QualifiedIdentifier,
+    ) -> bool {
+        // The value and attributes of each definition, but not the identifier, nor any
+        // comments...
+        fn content<'a>(
+            document: &'a FluentDocument,
+            identifier: &QualifiedIdentifier,
+        ) -> Vec<(Option<&'a Pattern>, &'a [Attribute])> {
+            Vec::from_iter(document.entries(identifier).map(|entry| match entry {
+                Entry::Message(message) => (message.pattern(), message.attributes()),
+                Entry::Term(term) => (Some(term.pattern()), term.attributes()),
+                Entry::CommentLine(_) => (None, [].as_slice()),
+            }))
+        }
+
+        let is_allowed = settings
+            .allowed_untranslated_copies
+            .contains(&identifier.to_meta_string())
+            || canonical
+                .comments(identifier)
+                .chain(primary.comments(identifier))
+                .any(|comment| comment == ALLOW_COPY_MARKER);
+
+        canonical.language_root() != primary.language_root()
+            && !is_allowed
+            && canonical.has_text(identifier)
+            && content(canonical, identifier) == content(primary, identifier)
+    }
+
     pub fn emit_base_to_variant_issues(&mut self) {
         self.state
             .canonical
@@ -335,17 +550,23 @@ impl Pipeline<DocumentsClassified> {
                         base_identifiers
                             .intersection(&variant_identifiers)
                             .for_each(|i| {
-                                if base.signature(i) != variant.signature(i) {
-                                    self.issues
-                                        .push(AuditIssue::signature_mismatch(variant.locale(), i));
+                                if let Some(diff) = Self::signature_diff(base, variant, i) {
+                                    self.issues.push(
+                                        AuditIssue::signature_mismatch(variant.locale(), i, diff)
+                                            .with_locations(
+                                                variant.locations(i).chain(base.locations(i)),
+                                            ),
+                                    );
                                 }
                             });
 
                         variant_identifiers
                             .difference(&base_identifiers)
                             .for_each(|i| {
-                                self.issues
-                                    .push(AuditIssue::redundant_translation(variant.locale(), i));
+                                self.issues.push(
+                                    AuditIssue::redundant_translation(variant.locale(), i)
+                                        .with_locations(variant.locations(i)),
+                                );
                             });
                     });
             });
@@ -356,18 +577,55 @@ impl Pipeline<DocumentsClassified> {
             use std::str::FromStr;
 
             let identifiers = Vec::from_iter(canonical_document.all_identifiers());
+            let literals = Vec::from_iter(
+                canonical_document
+                    .public_identifiers()
+                    .filter_map(|identifier| identifier.to_literal_string()),
+            );
 
             self.state.rust_files.iter().for_each(|f| {
                 f.macro_calls().for_each(|call| {
-                    match QualifiedIdentifier::from_str(call.literal()) {
+                    if let Some(pattern) = call.pattern() {
+                        if !literals.iter().any(|literal| pattern.matches(literal)) {
+                            self.issues
+                                .push(AuditIssue::unmatched_identifier_pattern(f, call));
+                        }
+                        return;
+                    }
+
+                    let Some(literal) = call.literal() else {
+                        self.issues
+                            .push(AuditIssue::unverifiable_identifier(f, call));
+                        return;
+                    };
+
+                    match QualifiedIdentifier::from_str(literal) {
                         Ok(identifier) => {
                             if !identifiers.contains(&identifier) {
-                                self.issues
-                                    .push(AuditIssue::undefined_identifier_literal(f, &identifier))
+                                self.issues.push(
+                                    AuditIssue::undefined_identifier_literal(f, call, &identifier)
+                                        .with_suggestions(suggestions(
+                                            literal,
+                                            literals.iter().map(String::as_str),
+                                        )),
+                                )
+                            } else if identifier.path().is_public()
+                                && let Some(arguments) = call.arguments()
+                                && let Some(variables) = canonical_document.variables(&identifier)
+                            {
+                                Self::emit_argument_issues(
+                                    &mut self.issues,
+                                    f,
+                                    call,
+                                    &identifier,
+                                    Vec::from_iter(arguments),
+                                    variables,
+                                );
                             }
                         }
                         Err(error) => self.issues.push(AuditIssue::malformed_identifier_literal(
                             f,
+                            call,
                             &error.to_string(),
                         )),
                     }
@@ -375,6 +633,158 @@ impl Pipeline<DocumentsClassified> {
             });
         }
     }
+
+    /// Compares the named arguments of a macro call with the variables of the canonical
+    /// entry. A message call expects only the variables of the message's value, and an
+    /// attribute call only the attribute's own, as each is formatted separately.
+    fn emit_argument_issues(
+        issues: &mut Vec<AuditIssue>,
+        file: &ParsedRustFile,
+        call: &MacroCall,
+        identifier: &QualifiedIdentifier,
+        arguments: Vec<&str>,
+        variables: Vec<&str>,
+    ) {
+        let variables = Vec::from_iter(
+            variables
+                .into_iter()
+                .map(|variable| variable.trim_start_matches('$')),
+        );
+
+        variables
+            .iter()
+            .filter(|variable| !arguments.contains(variable))
+            .for_each(|variable| {
+                issues.push(AuditIssue::missing_argument(
+                    file, call, identifier, variable,
+                ))
+            });
+
+        arguments
+            .iter()
+            .filter(|argument| !variables.contains(argument))
+            .for_each(|argument| {
+                issues.push(AuditIssue::unexpected_argument(
+                    file, call, identifier, argument,
+                ))
+            });
+    }
+
+    fn apply_suppressions(&mut self) {
+        let suppressions = Vec::from_iter(
+            self.state
+                .canonical
+                .iter()
+                .chain(self.state.primaries.iter())
+                .chain(self.state.variants.iter())
+                .chain(self.state.orphans.iter())
+                .flat_map(|document| {
+                    let locale = document.locale();
+                    document.suppressions().map(move |s| (Some(locale), s))
+                })
+                .chain(
+                    self.state
+                        .rust_files
+                        .iter()
+                        .flat_map(|f| f.suppressions().map(|s| (None, s))),
+                ),
+        );
+        let issues = std::mem::take(&mut self.issues);
+        self.issues = apply_suppressions(&suppressions, issues);
+    }
+
+    fn apply_severity(&mut self) {
+        let issues = std::mem::take(&mut self.issues);
+        self.issues = issues
+            .into_iter()
+            .map(|issue| {
+                let severity = self.severity.severity(issue.kind());
+                issue.with_severity(severity)
+            })
+            .filter(|issue| issue.severity() != Severity::Off)
+            .collect();
+    }
+
+    /// Orders the issues by kind, then by where they were found, as they are otherwise
+    /// found in an order that varies from run to run, e.g. by iterating sets of
+    /// identifiers or analysing documents in parallel.
+    fn sort_issues(&mut self) {
+        self.issues.sort_by(|a, b| {
+            (a.kind(), a.locations(), a.subject(), a.message()).cmp(&(
+                b.kind(),
+                b.locations(),
+                b.subject(),
+                b.message(),
+            ))
+        });
+    }
+
+    pub fn emit_hardcoded_text_issues(&mut self) {
+        if !self.settings.hardcoded_text {
+            return;
+        }
+
+        let allowed = &self.settings.allowed_hardcoded_text;
+
+        self.state.rust_files.iter().for_each(|f| {
+            f.hardcoded_texts()
+                .filter(|text| !allowed.iter().any(|a| a == text.text().trim()))
+                .for_each(|text| self.issues.push(AuditIssue::hardcoded_text(f, text)));
+        });
+    }
+
+    pub fn emit_unused_identifiers(&mut self) {
+        if !self.settings.unused_identifiers {
+            return;
+        }
+
+        if let Some(canonical_document) = &self.state.canonical {
+            use std::str::FromStr;
+
+            let calls = Vec::from_iter(self.state.rust_files.iter().flat_map(|f| f.macro_calls()));
+            let patterns = Vec::from_iter(calls.iter().filter_map(|call| call.pattern()));
+
+            // Identifiers built by `format!` are taken to be any that match its pattern...
+            let matched = canonical_document
+                .public_identifiers()
+                .filter(|identifier| {
+                    identifier.to_literal_string().is_some_and(|literal| {
+                        patterns.iter().any(|pattern| pattern.matches(&literal))
+                    })
+                });
+
+            let used = calls
+                .iter()
+                .filter_map(|call| call.literal())
+                .filter_map(|literal| QualifiedIdentifier::from_str(literal).ok())
+                .chain(matched)
+                .chain(canonical_document.references())
+                .collect::<HashSet<_>>();
+
+            let used_roots = used.iter().map(|i| i.root()).collect::<HashSet<_>>();
+
+            let mut unused = canonical_document
+                .public_identifiers()
+                .filter(|i| {
+                    let root = i.root();
+                    if *i == root {
+                        !used_roots.contains(i)
+                    } else {
+                        used_roots.contains(&root) && !used.contains(i)
+                    }
+                })
+                .collect::<Vec<_>>();
+            unused.sort();
+
+            let locale = canonical_document.locale();
+            unused.iter().for_each(|i| {
+                self.issues.push(
+                    AuditIssue::unused_identifier(locale, i)
+                        .with_locations(canonical_document.locations(i)),
+                );
+            });
+        }
+    }
 }
 
 impl Pipeline<Audited> {
@@ -388,6 +798,24 @@ impl Pipeline<Audited> {
         docs.into_iter().map(move |d| (role, d.clone()))
     }
 
+    /// Takes the cache given by `Pipeline::with_cache`, keeping the audited documents so
+    /// that their analysis is reused by the next audit; an empty cache if none was given.
+    pub fn take_cache(&mut self) -> ParseCache {
+        let mut cache = self.cache.take().unwrap_or_default();
+
+        let state = &self.state;
+        cache.retain_documents(
+            state
+                .canonical
+                .iter()
+                .chain(state.primaries.iter())
+                .chain(state.variants.iter())
+                .chain(state.orphans.iter()),
+        );
+
+        cache
+    }
+
     pub fn get_result(self, workspace: &Workspace) -> AuditResult {
         let documents = Self::with_role(DocumentRole::Canonical, self.state.canonical)
             .chain(Self::with_role(DocumentRole::Primary, self.state.primaries))
@@ -396,7 +824,7 @@ impl Pipeline<Audited> {
             .map(|(role, document)| AuditedDocument::from_fluent_document(role, &document))
             .collect::<Vec<_>>();
 
-        AuditResult::new(self.issues, documents, workspace)
+        AuditResult::new(self.issues, documents, self.state.macro_calls, workspace)
     }
 }
 
@@ -419,6 +847,152 @@ mod test {
         );
     }
 
+    fn locations_of(issues: &[AuditIssue], kind: Kind) -> Vec<(usize, usize)> {
+        issues
+            .iter()
+            .filter(|i| i.kind() == &kind)
+            .flat_map(|i| i.locations())
+            .map(|l| (l.line(), l.column()))
+            .collect()
+    }
+
+    #[test]
+    fn fluent_issues_have_source_locations() {
+        with_temp_fluent_files(
+            &[
+                (
+                    "en-GB",
+                    r#"
+message = Hello
+    .attr = Attribute
+message = Hello again
+greeting = { $name }
+referrer = { missing }
+"#,
+                ),
+                (
+                    "it-IT",
+                    r#"
+# Comment
+greeting = Ciao
+redundant = Ridondante
+"#,
+                ),
+            ],
+            |files| {
+                let canonical = locale("en-GB");
+                let primaries = [locale("it-IT")];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(files, &[])
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                let issues = &pipeline.issues;
+                assert_eq!(
+                    locations_of(issues, Kind::DuplicateIdentifier),
+                    [(1, 1), (3, 1)]
+                );
+                assert_eq!(locations_of(issues, Kind::InvalidReference), [(5, 1)]);
+                assert_eq!(
+                    locations_of(issues, Kind::SignatureMismatch),
+                    [(2, 1), (4, 1)]
+                );
+                assert_eq!(locations_of(issues, Kind::RedundantTranslation), [(3, 1)]);
+
+                let issue = issues
+                    .iter()
+                    .find(|i| i.kind() == &Kind::SignatureMismatch)
+                    .expect("signature mismatch");
+                assert!(issue.locations()[0].path().ends_with("it-IT.ftl"));
+                assert!(issue.locations()[1].path().ends_with("en-GB.ftl"));
+
+                let mut missing = locations_of(issues, Kind::MissingTranslation);
+                missing.sort();
+                assert_eq!(missing, [(1, 1), (3, 1), (5, 1)]);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_and_attribute_issues_have_source_locations() {
+        let fluent_files = vec![(
+            "en-GB",
+            r#"
+message = Hello
+    .used = Used
+    .unused = Unused
+"#,
+        )];
+
+        let rust_files = vec![(
+            "source",
+            r#"
+fn function() {
+    let _ = t!("message.used");
+    let _ = t!("undefined");
+    rsx! {
+        span { title: "{t!(\"interpolated\")}" }
+    }
+}
+"#,
+        )];
+
+        with_temp_fluent_files(&fluent_files, |fluent_files| {
+            with_temp_rust_files(&rust_files, |rust_files| {
+                let settings = AuditSettings {
+                    unused_identifiers: true,
+                    ..Default::default()
+                };
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::with_settings(&settings)
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                let issues = &pipeline.issues;
+                assert_eq!(locations_of(issues, Kind::UnusedIdentifier), [(3, 5)]);
+
+                let mut undefined = locations_of(issues, Kind::UndefinedIdentifierLiteral);
+                undefined.sort();
+                assert_eq!(undefined, [(3, 16), (5, 23)]);
+            });
+        });
+    }
+
+    #[test]
+    fn parse_errors_have_source_locations() {
+        with_temp_fluent_files(
+            &[(
+                "en-GB",
+                r#"
+message = Hello
+kdahf(#Q)$)(
+"#,
+            )],
+            |fluent_files| {
+                with_temp_rust_files(
+                    &[("source", "fn function() {\n    let x = ;\n}")],
+                    |rust_files| {
+                        let pipeline = Pipeline::default()
+                            .parse_files(fluent_files, rust_files)
+                            .expect("valid pipeline");
+
+                        let mut locations = locations_of(&pipeline.issues, Kind::ParseError);
+                        locations.sort();
+                        assert_eq!(locations, [(2, 1), (2, 13)]);
+                    },
+                );
+            },
+        );
+    }
+
     #[test]
     fn parse_errors() {
         with_temp_fluent_files(
@@ -736,14 +1310,12 @@ message4 =
                     r#"
 emails1 =
     { $unreadEmails ->
-        [one] You have one unread email.
-        [two] You have two unread emails.
+        [0] You have no unread emails.
         *[other] You have { $unreadEmails } unread emails.
     }
 emails2 =
     { $unreadEmails ->
-        [one] You have one unread email.
-        [two] You have two unread emails.
+        [0] You have no unread emails.
         *[other] You have { $unreadEmails } unread emails.
     }
 "#,
@@ -753,13 +1325,11 @@ emails2 =
                     r#"
 emails1 =
     { $unreadEmails ->
-        [one] Hai un'email non letta.
         *[other] Hai { $unreadEmails } email non lette.
     }
 emails2 =
     { $unreadEmails ->
-        [one] Hai un'email non letta.
-        [two] Hai due email non lette.
+        [0] Non hai email non lette.
         *[other] Hai { $unreadEmails } email non lette.
     }
 "#,
@@ -834,7 +1404,7 @@ message2 = Buongiorno it 2
     }
 
     #[test]
-    fn base_to_variant_missing_translations_is_valid() {
+    fn canonical_to_primary_untranslated_copies() {
         with_temp_fluent_files(
             &[
                 (
@@ -842,72 +1412,80 @@ message2 = Buongiorno it 2
                     r#"
 message1 = Hello
 message2 = Hello again
+    .title = Title
+message3 = { $first } { $last }
+brand = Lingora
+# lingora-allow-copy
+ok = OK
+-term = World
+# lingora-allow-copy
+
+detached = Detached
 "#,
                 ),
                 (
-                    "en-AU",
+                    "it-IT",
                     r#"
-message1 = G'day
+message1 = Buongiorno
+# Da tradurre
+message2 = Hello again
+    .title = Title
+message3 = { $first } { $last }
+brand = Lingora
+ok = OK
+-term = World
+detached = Detached
 "#,
                 ),
             ],
             |files| {
                 let canonical = locale("en-GB");
-                let primaries = [];
+                let primaries = [locale("it-IT")];
+                let settings = AuditSettings {
+                    allowed_untranslated_copies: vec!["brand".into()],
+                    ..Default::default()
+                };
 
-                let pipeline = Pipeline::default()
+                let pipeline = Pipeline::with_settings(&settings)
                     .parse_files(files, &[])
                     .expect("valid pipeline")
                     .collect_documents_by_locale()
                     .classify_documents(&canonical, &primaries)
                     .audit();
 
-                assert_eq!(pipeline.issues.len(), 0);
+                assert_eq!(pipeline.issues.len(), 3);
+
+                ["message2", "-term", "detached"].iter().for_each(|i| {
+                    assert_issue_has(
+                        &pipeline.issues,
+                        Kind::UntranslatedCopy,
+                        Subject::Entry(locale("it-IT"), identifier(i)),
+                    );
+                });
             },
         );
     }
 
     #[test]
-    fn base_to_variant_signature_mismatch_issues() {
+    fn canonical_to_primary_untranslated_copies_within_language_root_are_valid() {
         with_temp_fluent_files(
             &[
                 (
                     "en-GB",
                     r#"
-emails1 =
-    { $unreadEmails ->
-        [one] You have one unread email.
-        [two] You have two unread emails.
-        *[other] You have { $unreadEmails } unread emails.
-    }
-emails2 =
-    { $unreadEmails ->
-        [one] You have one unread email.
-        [two] You have two unread emails.
-        *[other] You have { $unreadEmails } unread emails.
-    }
+message1 = Hello
 "#,
                 ),
                 (
-                    "en-AU",
+                    "en-US",
                     r#"
-emails1 =
-    { $unreadEmails ->
-        [one] You have one unread email.
-        *[other] You have { $unreadEmails } unread emails.
-    }
-emails2 =
-    { $unreadEmails ->
-        [one] You have one unread email.
-        [two] You have two unread emails.
-        *[other] You have { $unreadEmails } unread emails.
-    }
+message1 = Hello
 "#,
                 ),
             ],
             |files| {
                 let canonical = locale("en-GB");
-                let primaries = [];
+                let primaries = [locale("en-US")];
 
                 let pipeline = Pipeline::default()
                     .parse_files(files, &[])
@@ -916,19 +1494,103 @@ emails2 =
                     .classify_documents(&canonical, &primaries)
                     .audit();
 
-                assert_eq!(pipeline.issues.len(), 1);
-
-                assert_issue_has(
-                    &pipeline.issues,
-                    Kind::SignatureMismatch,
-                    Subject::Entry(locale("en-AU"), identifier("emails1")),
-                );
+                assert_eq!(pipeline.issues.len(), 0);
             },
         );
     }
 
     #[test]
-    fn base_to_variant_redundant_translation_issues() {
+    fn base_to_variant_missing_translations_is_valid() {
+        with_temp_fluent_files(
+            &[
+                (
+                    "en-GB",
+                    r#"
+message1 = Hello
+message2 = Hello again
+"#,
+                ),
+                (
+                    "en-AU",
+                    r#"
+message1 = G'day
+"#,
+                ),
+            ],
+            |files| {
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(files, &[])
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                assert_eq!(pipeline.issues.len(), 0);
+            },
+        );
+    }
+
+    #[test]
+    fn base_to_variant_signature_mismatch_issues() {
+        with_temp_fluent_files(
+            &[
+                (
+                    "en-GB",
+                    r#"
+emails1 =
+    { $unreadEmails ->
+        [0] You have no unread emails.
+        *[other] You have { $unreadEmails } unread emails.
+    }
+emails2 =
+    { $unreadEmails ->
+        [0] You have no unread emails.
+        *[other] You have { $unreadEmails } unread emails.
+    }
+"#,
+                ),
+                (
+                    "en-AU",
+                    r#"
+emails1 =
+    { $unreadEmails ->
+        *[other] You have { $unreadEmails } unread emails.
+    }
+emails2 =
+    { $unreadEmails ->
+        [0] You have no unread emails.
+        *[other] You have { $unreadEmails } unread emails.
+    }
+"#,
+                ),
+            ],
+            |files| {
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(files, &[])
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                assert_eq!(pipeline.issues.len(), 1);
+
+                assert_issue_has(
+                    &pipeline.issues,
+                    Kind::SignatureMismatch,
+                    Subject::Entry(locale("en-AU"), identifier("emails1")),
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn base_to_variant_redundant_translation_issues() {
         with_temp_fluent_files(
             &[
                 (
@@ -1288,6 +1950,167 @@ fn function() {
         });
     }
 
+    #[test]
+    fn unused_identifiers_are_not_reported_by_default() {
+        let fluent_files = vec![(
+            "en-GB",
+            r#"
+message1 = Hello en 1
+message2 = Hello en 2
+"#,
+        )];
+
+        let rust_files = vec![(
+            "used",
+            r#"
+fn function() {
+    let message1 = t!("message1");
+}
+"#,
+        )];
+
+        with_temp_fluent_files(&fluent_files, |fluent_files| {
+            with_temp_rust_files(&rust_files, |rust_files| {
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                assert_eq!(pipeline.issues.len(), 0);
+            });
+        });
+    }
+
+    #[test]
+    fn hardcoded_text_is_reported_when_enabled() {
+        let fluent_files = vec![(
+            "en-GB",
+            r#"
+save = Save
+"#,
+        )];
+
+        let rust_files = vec![(
+            "app",
+            r#"
+fn app() -> Element {
+    rsx! {
+        button { title: "Save changes", {t!("save")} }
+        p { "Powered by" }
+        footer { "Lingora" }
+        span { "·" }
+    }
+}
+"#,
+        )];
+
+        with_temp_fluent_files(&fluent_files, |fluent_files| {
+            with_temp_rust_files(&rust_files, |rust_files| {
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                assert!(pipeline.issues.is_empty());
+
+                let settings = AuditSettings {
+                    hardcoded_text: true,
+                    allowed_hardcoded_text: vec!["Lingora".into()],
+                    ..Default::default()
+                };
+
+                let pipeline = Pipeline::with_settings(&settings)
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                assert_eq!(
+                    messages_of(&pipeline.issues),
+                    [
+                        "hardcoded text 'Save changes' in title attribute",
+                        "hardcoded text 'Powered by'",
+                    ]
+                );
+                assert_eq!(
+                    locations_of(&pipeline.issues, Kind::HardcodedText),
+                    [(3, 25), (4, 13)]
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn unused_identifiers() {
+        let fluent_files = vec![(
+            "en-GB",
+            r#"
+used = Hello
+unused = Hello again
+referenced = World
+referencing = { referenced }
+attributes = Attributes
+    .used = Used
+    .unused = Unused
+unused-attributes =
+    .unused = Unused
+-term = Terms are private
+"#,
+        )];
+
+        let rust_files = vec![(
+            "used",
+            r#"
+fn function() {
+    let used = t!("used");
+    let referencing = t!("referencing");
+    let attribute = t!("attributes.used");
+}
+"#,
+        )];
+
+        with_temp_fluent_files(&fluent_files, |fluent_files| {
+            with_temp_rust_files(&rust_files, |rust_files| {
+                let canonical = locale("en-GB");
+                let primaries = [];
+                let settings = AuditSettings {
+                    unused_identifiers: true,
+                    ..Default::default()
+                };
+
+                let pipeline = Pipeline::with_settings(&settings)
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                assert_eq!(pipeline.issues.len(), 3);
+
+                ["unused", "attributes.unused", "unused-attributes"]
+                    .iter()
+                    .for_each(|i| {
+                        assert_issue_has(
+                            &pipeline.issues,
+                            Kind::UnusedIdentifier,
+                            Subject::Entry(locale("en-GB"), identifier(i)),
+                        );
+                    });
+            });
+        });
+    }
+
     #[test]
     fn issue_53_pt2_ignore_non_literal_content() {
         let fluent_files = vec![(
@@ -1323,4 +2146,990 @@ fn Flag(lang: String) -> Element {
             });
         });
     }
+
+    fn audit_primary(base: &str, primary: &str) -> Vec<AuditIssue> {
+        let mut issues = Vec::new();
+        with_temp_fluent_files(&[("en-GB", base), ("it-IT", primary)], |files| {
+            let canonical = locale("en-GB");
+            let primaries = [locale("it-IT")];
+
+            let pipeline = Pipeline::default()
+                .parse_files(files, &[])
+                .expect("valid pipeline")
+                .collect_documents_by_locale()
+                .classify_documents(&canonical, &primaries)
+                .audit();
+
+            issues = pipeline.issues;
+        });
+        issues
+    }
+
+    #[test]
+    fn issues_are_ordered_by_kind_and_location_on_every_run() {
+        let base = r#"
+alpha = Alpha { $name }
+beta = Beta
+gamma = Gamma { $count }
+delta = Delta
+"#;
+        let primary = r#"
+alpha = Alfa
+gamma = Gamma
+epsilon = Epsilon
+zeta = Zeta
+"#;
+
+        let issues = audit_primary(base, primary);
+        let mut sorted = issues.clone();
+        sorted.sort_by(|a, b| {
+            (a.kind(), a.locations(), a.subject(), a.message()).cmp(&(
+                b.kind(),
+                b.locations(),
+                b.subject(),
+                b.message(),
+            ))
+        });
+
+        assert!(issues.len() > 4, "{issues:#?}");
+        assert_eq!(messages_of(&issues), messages_of(&sorted));
+        for _ in 0..8 {
+            assert_eq!(
+                messages_of(&audit_primary(base, primary)),
+                messages_of(&issues)
+            );
+        }
+    }
+
+    #[test]
+    fn entry_suppressions_silence_issues_of_the_entry() {
+        let issues = audit_primary(
+            r#"
+greeting = Hello { $name }
+farewell = Goodbye { $name }
+"#,
+            r#"
+# Informal; the name is not used.
+# lingora-ignore: signature-mismatch
+greeting = Ciao
+farewell = Arrivederci
+"#,
+        );
+
+        assert_eq!(issues.len(), 1, "{issues:#?}");
+        assert_issue_has(
+            &issues,
+            Kind::SignatureMismatch,
+            Subject::Entry(locale("it-IT"), identifier("farewell")),
+        );
+    }
+
+    #[test]
+    fn canonical_suppressions_do_not_silence_issues_of_other_locales() {
+        let issues = audit_primary(
+            r#"
+# lingora-ignore: signature-mismatch, missing-translation
+greeting = Hello { $name }
+# lingora-ignore: missing-translation
+farewell = Goodbye
+"#,
+            r#"
+greeting = Ciao
+"#,
+        );
+
+        assert_eq!(
+            Vec::from_iter(issues.iter().map(|i| (i.kind(), i.message().as_str()))),
+            [
+                (&Kind::MissingTranslation, "missing translation 'farewell'"),
+                (&Kind::SignatureMismatch, "signature mismatch 'greeting'"),
+                (
+                    &Kind::UnusedSuppression,
+                    "unused suppression 'missing-translation'"
+                ),
+                (
+                    &Kind::UnusedSuppression,
+                    "unused suppression 'signature-mismatch'"
+                ),
+                (
+                    &Kind::UnusedSuppression,
+                    "unused suppression 'missing-translation'"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn entry_suppressions_are_detached_by_blank_lines() {
+        let issues = audit_primary(
+            r#"
+greeting = Hello { $name }
+"#,
+            r#"
+# lingora-ignore: signature-mismatch
+
+greeting = Ciao
+"#,
+        );
+
+        assert_eq!(
+            Vec::from_iter(issues.iter().map(|i| i.kind())),
+            [&Kind::SignatureMismatch, &Kind::UnusedSuppression]
+        );
+    }
+
+    #[test]
+    fn file_suppressions_silence_issues_of_the_file() {
+        let issues = audit_primary(
+            r#"
+greeting = Hello { $name }
+farewell = Goodbye { $name }
+"#,
+            r#"
+### lingora-ignore-file: signature_mismatch, redundant-translation
+greeting = Ciao
+farewell = Arrivederci
+extra = Extra
+"#,
+        );
+
+        assert!(issues.is_empty(), "{issues:#?}");
+    }
+
+    #[test]
+    fn suppressions_without_kinds_silence_all_kinds() {
+        let issues = audit_primary(
+            r#"
+greeting = Hello { $name }
+"#,
+            r#"
+# lingora-ignore
+greeting = Ciao
+# lingora-ignore
+extra = Extra
+"#,
+        );
+
+        assert!(issues.is_empty(), "{issues:#?}");
+    }
+
+    #[test]
+    fn unused_suppressions_are_reported() {
+        let issues = audit_primary(
+            r#"
+greeting = Hello { $name }
+"#,
+            r#"
+# lingora-ignore: signature-mismatch, redundant-translation, no-such-kind
+greeting = Ciao
+"#,
+        );
+
+        let messages = Vec::from_iter(
+            issues
+                .iter()
+                .filter(|i| i.kind() == &Kind::UnusedSuppression)
+                .map(|i| i.message()),
+        );
+        assert_eq!(
+            messages,
+            [
+                "unused suppression 'redundant-translation'",
+                "unused suppression of unknown kind 'no-such-kind'"
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::UnusedSuppression),
+            [(1, 1), (1, 1)]
+        );
+        assert!(
+            issues
+                .iter()
+                .filter(|i| i.kind() == &Kind::UnusedSuppression)
+                .all(|i| i.subject() == &Subject::FluentFile(i.locations()[0].path().into()))
+        );
+    }
+
+    fn audit_rust(source: &str) -> Vec<AuditIssue> {
+        audit_rust_with("message = Hello\n", source)
+    }
+
+    fn audit_rust_with(canonical: &str, source: &str) -> Vec<AuditIssue> {
+        let mut issues = Vec::new();
+        with_temp_fluent_files(&[("en-GB", canonical)], |fluent_files| {
+            with_temp_rust_files(&[("source", source)], |rust_files| {
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                issues = pipeline.issues;
+            });
+        });
+        issues
+    }
+
+    fn messages_of(issues: &[AuditIssue]) -> Vec<&str> {
+        Vec::from_iter(issues.iter().map(|i| i.message().as_str()))
+    }
+
+    #[test]
+    fn undefined_identifiers_and_references_suggest_the_nearest_identifiers() {
+        let issues = audit_rust_with(
+            r#"
+login-button = Log in
+login-link = Log in here
+-brand-name = Lingora
+welcome = Welcome to { -brnad-name }
+"#,
+            r#"
+fn app() {
+    let button = t!("login-buton");
+    let farewell = t!("farewell");
+}
+"#,
+        );
+
+        let suggestions_of = |kind: Kind| {
+            Vec::from_iter(
+                issues
+                    .iter()
+                    .filter(|i| *i.kind() == kind)
+                    .map(|i| (i.message().as_str(), i.suggestions().to_vec())),
+            )
+        };
+
+        assert_eq!(
+            suggestions_of(Kind::UndefinedIdentifierLiteral),
+            [
+                (
+                    "identifier literal login-buton is not defined in the canonical document",
+                    vec![String::from("login-button")]
+                ),
+                (
+                    "identifier literal farewell is not defined in the canonical document",
+                    vec![]
+                ),
+            ]
+        );
+        assert_eq!(
+            suggestions_of(Kind::InvalidReference),
+            [(
+                "invalid reference '-brnad-name'",
+                vec![String::from("-brand-name")]
+            )]
+        );
+    }
+
+    #[test]
+    fn rust_attribute_suppressions_silence_macro_calls_in_the_item() {
+        let issues = audit_rust(
+            r#"
+#[lingora::allow(undefined_identifier_literal)]
+fn fixture() {
+    let _ = t!("other-bundle");
+}
+
+fn function() {
+    #[cfg_attr(any(), lingora::allow(malformed_identifier_literal))]
+    let _ = t!("not valid");
+    #[lingora::allow(undefined_identifier)]
+    let _ = t!("short-name");
+    let _ = t!("missing");
+}
+"#,
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            ["identifier literal missing is not defined in the canonical document"],
+            "{issues:#?}"
+        );
+    }
+
+    #[test]
+    fn rust_comment_suppressions_silence_macro_calls_in_the_statement() {
+        let issues = audit_rust(
+            r#"
+fn function() {
+    // lingora:allow(undefined_identifier_literal)
+    let _ = t!(
+        "other-bundle"
+    );
+    // lingora:allow(undefined-identifier)
+    let _ = t!("short-name");
+    let _ = t!("feature-gated"); // lingora:allow
+    let _ = t!("missing");
+}
+"#,
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            ["identifier literal missing is not defined in the canonical document"],
+            "{issues:#?}"
+        );
+    }
+
+    #[test]
+    fn rust_inner_attribute_suppressions_silence_the_file() {
+        let issues = audit_rust(
+            r#"
+#![cfg_attr(any(), lingora::allow)]
+
+fn function() {
+    let _ = t!("other-bundle");
+    let _ = t!("not valid");
+}
+"#,
+        );
+
+        assert!(issues.is_empty(), "{issues:#?}");
+    }
+
+    #[test]
+    fn unused_rust_suppressions_are_reported() {
+        let issues = audit_rust(
+            r#"
+#[lingora::allow(undefined_identifier_literal)]
+fn function() {
+    let _ = t!("message");
+}
+
+// lingora:allow
+"#,
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "unused suppression 'undefined_identifier_literal'",
+                "unused suppression"
+            ]
+        );
+        assert!(
+            issues
+                .iter()
+                .all(|i| matches!(i.subject(), Subject::RustFile(_)))
+        );
+    }
+
+    #[test]
+    fn macro_arguments_are_checked_against_canonical_variables() {
+        let issues = audit_rust_with(
+            r#"
+emails = { $num ->
+    [one] One email for { $user }
+   *[other] { $num } emails for { $user }
+}
+login = Log in
+    .title = Log in as { $user }
+"#,
+            r#"
+fn fixture() {
+    let _ = t!("emails", count: n, user: name);
+    let _ = t!("emails", num: n.max(1), user: format!("{a}, {b}"));
+    let _ = te!("login.title");
+    let _ = tid!("login", user: name);
+}
+"#,
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "missing argument 'num' for 'emails'",
+                "unexpected argument 'count' for 'emails'",
+                "missing argument 'user' for 'login / .title'",
+                "unexpected argument 'user' for 'login'",
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::ArgumentMismatch),
+            [(2, 16), (2, 16), (4, 17), (5, 18)]
+        );
+    }
+
+    #[test]
+    fn message_arguments_exclude_the_attribute_variables() {
+        let issues = audit_rust_with(
+            r#"
+login = Log in
+    .title = Log in as { $user }
+"#,
+            r#"
+fn fixture() {
+    let _ = t!("login");
+    let _ = t!("login.title", user: name);
+}
+"#,
+        );
+
+        assert!(issues.is_empty(), "unexpected issues {issues:#?}");
+    }
+
+    #[test]
+    fn attribute_arguments_are_checked_against_the_attribute_variables() {
+        let issues = audit_rust_with(
+            r#"
+greeting = Hello { $name }
+    .title = Welcome back, { $user }
+    .label = Greeting
+"#,
+            r#"
+fn fixture() {
+    let _ = t!("greeting.title", user: name);
+    let _ = t!("greeting.label");
+    let _ = t!("greeting.title", name: name);
+}
+"#,
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "missing argument 'user' for 'greeting / .title'",
+                "unexpected argument 'name' for 'greeting / .title'",
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::ArgumentMismatch),
+            [(4, 16), (4, 16)]
+        );
+    }
+
+    #[test]
+    fn configured_macros_are_scanned_at_their_identifier_argument() {
+        use std::str::FromStr;
+
+        use crate::config::LingoraToml;
+
+        let toml = LingoraToml::from_str(
+            r#"
+[rust_scan]
+macros = [
+    { name = "i18n_embed_fl::fl", argument = 1 },
+    { name = "tr" },
+]
+"#,
+        )
+        .expect("valid toml");
+
+        let mut issues = Vec::new();
+        with_temp_fluent_files(
+            &[("en-GB", "message = Hello { $user }\n")],
+            |fluent_files| {
+                with_temp_rust_files(
+                    &[(
+                        "source",
+                        r#"
+fn fixture() {
+    let _ = i18n_embed_fl::fl!(LOADER, "message", user = name);
+    let _ = fl!(LOADER, "unqualified");
+    let _ = crate::tr!("undefined");
+    let _ = t!("not-configured");
+    rsx! { "{tr!(\"message\")}" }
+}
+"#,
+                    )],
+                    |rust_files| {
+                        let canonical = locale("en-GB");
+                        let primaries = [];
+
+                        let pipeline = Pipeline::default()
+                            .with_rust_scan(&toml.rust_scan)
+                            .parse_files(fluent_files, rust_files)
+                            .expect("valid pipeline")
+                            .collect_documents_by_locale()
+                            .classify_documents(&canonical, &primaries)
+                            .audit();
+
+                        issues = pipeline.issues;
+                    },
+                );
+            },
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "identifier literal unqualified is not defined in the canonical document",
+                "identifier literal undefined is not defined in the canonical document",
+                "missing argument 'user' for 'message'",
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::UndefinedIdentifierLiteral),
+            [(3, 25), (4, 24)]
+        );
+    }
+
+    #[test]
+    fn configured_functions_and_methods_are_scanned_at_their_identifier_argument() {
+        use std::str::FromStr;
+
+        use crate::config::LingoraToml;
+
+        let toml = LingoraToml::from_str(
+            r#"
+[rust_scan]
+functions = [
+    { name = "lookup", argument = 1 },
+    { name = "get_message" },
+    { name = "i18n::translate" },
+]
+"#,
+        )
+        .expect("valid toml");
+
+        let mut issues = Vec::new();
+        with_temp_fluent_files(
+            &[("en-GB", "message = Hello { $user }\n")],
+            |fluent_files| {
+                with_temp_rust_files(
+                    &[(
+                        "source",
+                        r#"
+fn fixture() {
+    let _ = LOCALES.lookup(&langid, "message");
+    let _ = bundle.get_message(&"undefined-message");
+    let _ = crate::i18n::translate("undefined-function");
+    let _ = other::translate("not-configured");
+    let _ = lookup(&langid, "undefined-lookup");
+    rsx! { "{LOCALES.lookup(&langid, \"undefined-in-rsx\")}" }
+}
+"#,
+                    )],
+                    |rust_files| {
+                        let canonical = locale("en-GB");
+                        let primaries = [];
+
+                        let pipeline = Pipeline::default()
+                            .with_rust_scan(&toml.rust_scan)
+                            .parse_files(fluent_files, rust_files)
+                            .expect("valid pipeline")
+                            .collect_documents_by_locale()
+                            .classify_documents(&canonical, &primaries)
+                            .audit();
+
+                        issues = pipeline.issues;
+                    },
+                );
+            },
+        );
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "identifier literal undefined-message is not defined in the canonical document",
+                "identifier literal undefined-function is not defined in the canonical document",
+                "identifier literal undefined-lookup is not defined in the canonical document",
+                "identifier literal undefined-in-rsx is not defined in the canonical document",
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::UndefinedIdentifierLiteral),
+            [(3, 33), (4, 36), (6, 29), (7, 12)]
+        );
+    }
+
+    #[test]
+    fn identifiers_passed_through_constants_are_resolved() {
+        let mut issues = Vec::new();
+        with_temp_fluent_files(&[("en-GB", "message = Hello\n")], |fluent_files| {
+            with_temp_rust_files(
+                &[
+                    (
+                        "keys",
+                        r#"
+pub const SHARED_KEY: &str = "message";
+pub static UNDEFINED_KEY: &str = "undefined";
+pub const AMBIGUOUS_KEY: &str = "message";
+"#,
+                    ),
+                    ("other_keys", r#"pub const AMBIGUOUS_KEY: &str = "other";"#),
+                    (
+                        "source",
+                        r#"
+use crate::keys::{AMBIGUOUS_KEY, SHARED_KEY};
+
+const LOCAL_KEY: &str = "message";
+
+fn fixture(key: &str, keys: &[&str]) {
+    let _ = t!(LOCAL_KEY);
+    let _ = t!(SHARED_KEY);
+    let _ = t!(keys::UNDEFINED_KEY);
+    let _ = t!(AMBIGUOUS_KEY);
+    let _ = t!(key);
+    let _ = t!(&keys[0]);
+}
+"#,
+                    ),
+                ],
+                |rust_files| {
+                    let canonical = locale("en-GB");
+                    let primaries = [];
+
+                    let pipeline = Pipeline::default()
+                        .parse_files(fluent_files, rust_files)
+                        .expect("valid pipeline")
+                        .collect_documents_by_locale()
+                        .classify_documents(&canonical, &primaries)
+                        .audit();
+
+                    issues = pipeline.issues;
+                },
+            );
+        });
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "identifier literal undefined is not defined in the canonical document",
+                "identifier AMBIGUOUS_KEY passed to t cannot be resolved to a literal",
+                "identifier key passed to t cannot be resolved to a literal",
+                "identifier keys[0] passed to t cannot be resolved to a literal",
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::UnverifiableIdentifier),
+            [(9, 16), (10, 16), (11, 17)]
+        );
+        assert!(
+            issues
+                .iter()
+                .filter(|i| *i.kind() == Kind::UnverifiableIdentifier)
+                .all(|i| i.severity() == Severity::Info)
+        );
+    }
+
+    #[test]
+    fn identifiers_passed_as_other_expressions_are_unverifiable() {
+        use std::str::FromStr;
+
+        use crate::config::LingoraToml;
+
+        let toml = LingoraToml::from_str(
+            r#"
+[rust_scan]
+functions = [{ name = "get_message" }]
+"#,
+        )
+        .expect("valid toml");
+
+        let mut issues = Vec::new();
+        with_temp_fluent_files(&[("en-GB", "message = Hello\n")], |fluent_files| {
+            with_temp_rust_files(
+                &[(
+                    "source",
+                    r#"
+fn fixture(key: String, template: &str, names: &[&str]) {
+    let _ = t!(key.as_str());
+    let _ = t!(&format!(template, key));
+    let _ = te!(42);
+    let _ = bundle.get_message(&names[1]);
+    let _ = get_message(key.to_lowercase());
+}
+"#,
+                )],
+                |rust_files| {
+                    let canonical = locale("en-GB");
+                    let primaries = [];
+
+                    let pipeline = Pipeline::default()
+                        .with_rust_scan(&toml.rust_scan)
+                        .parse_files(fluent_files, rust_files)
+                        .expect("valid pipeline")
+                        .collect_documents_by_locale()
+                        .classify_documents(&canonical, &primaries)
+                        .audit();
+
+                    issues = pipeline.issues;
+                },
+            );
+        });
+
+        assert_eq!(
+            messages_of(&issues),
+            [
+                "identifier key.as_str() passed to t cannot be resolved to a literal",
+                "identifier format!(template, key) passed to t cannot be resolved to a literal",
+                "identifier 42 passed to te cannot be resolved to a literal",
+                "identifier names[1] passed to get_message cannot be resolved to a literal",
+                "identifier key.to_lowercase() passed to get_message cannot be resolved to a literal",
+            ]
+        );
+        assert_eq!(
+            locations_of(&issues, Kind::UnverifiableIdentifier),
+            [(2, 16), (3, 17), (4, 17), (5, 33), (6, 25)]
+        );
+    }
+
+    #[test]
+    fn format_identifier_patterns_are_matched_against_canonical_identifiers() {
+        let fluent_files = vec![(
+            "en-GB",
+            r#"
+status-active = Active
+status-idle = Idle
+lang =
+    .en = English
+    .it = Italian
+unused = Unused
+"#,
+        )];
+
+        let rust_files = vec![(
+            "source",
+            r#"
+fn fixture(status: &str, lang: &str) -> Element {
+    let _ = t!(&format!("status-{}", status));
+    let _ = t!(&std::format!("missing-{status}"));
+    rsx! { "{t!(&format!(\"lang.{lang}\"))}" }
+}
+"#,
+        )];
+
+        with_temp_fluent_files(&fluent_files, |fluent_files| {
+            with_temp_rust_files(&rust_files, |rust_files| {
+                let settings = AuditSettings {
+                    unused_identifiers: true,
+                    ..Default::default()
+                };
+                let canonical = locale("en-GB");
+                let primaries = [];
+
+                let pipeline = Pipeline::with_settings(&settings)
+                    .parse_files(fluent_files, rust_files)
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                let issues = &pipeline.issues;
+                assert_eq!(
+                    messages_of(issues),
+                    [
+                        "identifier pattern missing-* matches no identifier in the canonical document",
+                        "unused identifier 'unused'",
+                    ]
+                );
+                assert_eq!(
+                    locations_of(issues, Kind::UnmatchedIdentifierPattern),
+                    [(3, 17)]
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn plural_categories_are_validated_per_locale() {
+        with_temp_fluent_files(
+            &[
+                (
+                    "en-GB",
+                    r#"
+emails = { $count ->
+    [one] One email
+    [few] A few emails
+   *[other] { $count } emails
+}
+place = { NUMBER($position, type: "ordinal") ->
+    [one] { $position }st
+    [two] { $position }nd
+    [few] { $position }rd
+   *[other] { $position }th
+}
+greeting = { $gender ->
+    [male] Welcome, sir
+    [female] Welcome, madam
+   *[other] Welcome
+}
+"#,
+                ),
+                (
+                    "pl-PL",
+                    r#"
+emails = { $count ->
+    [one] Jeden e-mail
+    [0] Brak e-maili
+   *[other] { $gender ->
+        [male] { $count } e-maili
+       *[other] { $count } e-maili
+    }
+}
+place = { NUMBER($position, type: "ordinal") ->
+   *[other] { $position }.
+}
+greeting = { $gender ->
+    [male] Witaj
+   *[other] Witaj
+}
+"#,
+                ),
+            ],
+            |files| {
+                let canonical = locale("en-GB");
+                let primaries = [locale("pl-PL")];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(files, &[])
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                let plural_issues = Vec::from_iter(
+                    pipeline
+                        .issues
+                        .iter()
+                        .filter(|i| {
+                            matches!(
+                                i.kind(),
+                                Kind::ImpossiblePluralCategory | Kind::MissingPluralCategory
+                            )
+                        })
+                        .map(|i| (i.subject().to_string(), i.message().as_str())),
+                );
+
+                assert_eq!(
+                    plural_issues,
+                    [
+                        (
+                            String::from("en-GB :: emails"),
+                            "impossible plural category 'few' in 'emails'"
+                        ),
+                        (
+                            String::from("pl-PL :: emails"),
+                            "missing plural category 'few' in 'emails'"
+                        ),
+                        (
+                            String::from("pl-PL :: emails"),
+                            "missing plural category 'many' in 'emails'"
+                        ),
+                    ]
+                );
+                assert_eq!(
+                    locations_of(&pipeline.issues, Kind::ImpossiblePluralCategory),
+                    [(1, 1)]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn plural_categories_of_each_locale_are_not_signature_mismatches() {
+        let mut issues = Vec::new();
+        with_temp_fluent_files(
+            &[
+                (
+                    "en-GB",
+                    r#"
+items = { $count ->
+    [one] One item
+   *[other] { $count } items
+}
+place = { NUMBER($position, type: "ordinal") ->
+    [one] { $position }st
+    [two] { $position }nd
+    [few] { $position }rd
+   *[other] { $position }th
+}
+"#,
+                ),
+                (
+                    "pl-PL",
+                    r#"
+items = { $count ->
+    [one] Jeden element
+    [few] { $count } elementy
+    [many] { $count } elementów
+   *[other] { $count } elementu
+}
+place = { NUMBER($position, type: "ordinal") ->
+   *[other] { $position }.
+}
+"#,
+                ),
+            ],
+            |files| {
+                let canonical = locale("en-GB");
+                let primaries = [locale("pl-PL")];
+
+                let pipeline = Pipeline::default()
+                    .parse_files(files, &[])
+                    .expect("valid pipeline")
+                    .collect_documents_by_locale()
+                    .classify_documents(&canonical, &primaries)
+                    .audit();
+
+                issues = pipeline.issues;
+            },
+        );
+
+        assert!(issues.is_empty(), "unexpected issues {issues:#?}");
+    }
+
+    #[test]
+    fn signature_mismatches_describe_their_differences() {
+        let issues = audit_primary(
+            r#"
+greeting = Hello { $name }
+    .title = Greeting
+emails = { $count ->
+    [one] One email
+   *[other] { $count } emails
+}
+login =
+    .placeholder = Email
+"#,
+            r#"
+greeting = Ciao { $user } { $time }
+    .tooltip = Saluto
+emails = { $count ->
+    [0] Nessuna email
+    [one] Un'email
+   *[other] { $count } email
+}
+login = Accedi
+    .placeholder = Email
+"#,
+        );
+
+        let mut changes = Vec::from_iter(
+            issues
+                .iter()
+                .filter_map(|i| Some((i.subject().to_string(), i.signature_diff()?.changes()))),
+        );
+        changes.sort();
+
+        assert_eq!(
+            changes,
+            [
+                (
+                    String::from("it-IT :: emails"),
+                    vec![String::from("unexpected variant [0]")]
+                ),
+                (
+                    String::from("it-IT :: greeting"),
+                    vec![
+                        String::from("missing variable $name"),
+                        String::from("unexpected variable $time"),
+                        String::from("unexpected variable $user"),
+                        String::from("missing attribute .title"),
+                        String::from("unexpected attribute .tooltip"),
+                    ]
+                ),
+                (
+                    String::from("it-IT :: login"),
+                    vec![String::from("unexpected value")]
+                ),
+            ]
+        );
+    }
 }
@@ -1,15 +1,21 @@
 use std::collections::HashMap;
 
 use fluent4rs::ast::Entry;
+use serde::{Serialize, Serializer, ser::SerializeStruct};
 
 use crate::{
-    audit::{AuditIssue, Workspace},
-    domain::{HasLocale, Locale},
+    audit::{AuditIssue, Baseline, Workspace},
+    config::Severity,
+    domain::{HasLocale, Locale, SourceLocation},
     fluent::{FluentDocument, QualifiedIdentifier},
+    rust::MacroCall,
 };
 
 /// The classification of a Fluent document's role within the workspace during analysis.
-#[derive(Clone, Copy, Debug)]
+///
+/// Serialized in `snake_case`, e.g. `canonical`.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DocumentRole {
     /// The reference document against which all others are compared.
     Canonical,
@@ -24,6 +30,17 @@ pub enum DocumentRole {
     Orphan,
 }
 
+impl std::fmt::Display for DocumentRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocumentRole::Canonical => "canonical".fmt(f),
+            DocumentRole::Primary => "primary".fmt(f),
+            DocumentRole::Variant => "variant".fmt(f),
+            DocumentRole::Orphan => "orphan".fmt(f),
+        }
+    }
+}
+
 /// A Fluent document that has been parsed, assigned a role, and is ready for analysis.
 #[derive(Clone, Debug)]
 pub struct AuditedDocument {
@@ -48,10 +65,24 @@ impl AuditedDocument {
         self.document.entry_identifiers()
     }
 
+    /// Returns an iterator over the identifiers an application can look up directly, i.e.
+    /// messages and their attributes.
+    pub fn public_identifiers(&self) -> impl Iterator<Item = QualifiedIdentifier> {
+        self.document.public_identifiers()
+    }
+
     /// Returns an iterator over all AST `Entry` nodes matching the given identifier.
     pub fn entries(&self, identifier: &QualifiedIdentifier) -> impl Iterator<Item = &Entry> {
         self.document.entries(identifier)
     }
+
+    /// Returns the source locations at which the given identifier is defined.
+    pub fn locations(
+        &self,
+        identifier: &QualifiedIdentifier,
+    ) -> impl Iterator<Item = &SourceLocation> {
+        self.document.locations(identifier)
+    }
 }
 
 impl HasLocale for AuditedDocument {
@@ -60,11 +91,26 @@ impl HasLocale for AuditedDocument {
     }
 }
 
+/// Serialized as the document's locale, role and contributing file paths.
+impl Serialize for AuditedDocument {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut paths = Vec::from_iter(self.document.paths());
+        paths.sort();
+
+        let mut state = serializer.serialize_struct("AuditedDocument", 3)?;
+        state.serialize_field("locale", self.locale())?;
+        state.serialize_field("role", &self.role)?;
+        state.serialize_field("paths", &paths)?;
+        state.end()
+    }
+}
+
 /// The complete result of running an audit over a workspace.
 ///
 /// Contains:
 /// - All discovered localization issues
 /// - The set of parsed and classified documents, indexed by locale
+/// - The translation calls found in the Rust sources, each with its location
 /// - A reference to the original workspace configuration
 ///
 /// This type is what `AuditEngine` returns and what CLI/TUI renderers consume.
@@ -72,13 +118,21 @@ impl HasLocale for AuditedDocument {
 pub struct AuditResult {
     issues: Vec<AuditIssue>,
     documents: HashMap<Locale, AuditedDocument>,
+    macro_calls: Vec<(SourceLocation, MacroCall)>,
     workspace: Workspace,
 }
 
 impl AuditResult {
+    /// The version of the serialized (e.g. JSON) form of an `AuditResult`.
+    ///
+    /// Incremented whenever a field is removed or its meaning changes; adding fields does
+    /// not change the version.
+    pub const SCHEMA_VERSION: u32 = 1;
+
     pub(crate) fn new(
         issues: Vec<AuditIssue>,
         nodes: Vec<AuditedDocument>,
+        macro_calls: Vec<(SourceLocation, MacroCall)>,
         workspace: &Workspace,
     ) -> Self {
         let documents = nodes
@@ -91,13 +145,26 @@ impl AuditResult {
         Self {
             issues,
             documents,
+            macro_calls,
             workspace,
         }
     }
 
-    /// Returns `true` if no issues were found (clean audit).
+    /// Returns `true` if no `Severity::Error` issues were found; warnings are tolerated.
     pub fn is_ok(&self) -> bool {
-        self.issues.is_empty()
+        !self
+            .issues
+            .iter()
+            .any(|issue| issue.severity() == Severity::Error)
+    }
+
+    /// Removes the issues recorded in the baseline, so that only new issues remain, and
+    /// returns the fingerprints of the recorded issues that are no longer found.
+    pub fn apply_baseline(&mut self, baseline: &Baseline) -> Vec<String> {
+        let issues = std::mem::take(&mut self.issues);
+        let (issues, fixed) = baseline.partition(issues);
+        self.issues = issues;
+        fixed
     }
 
     /// Returns the workspace configuration used for this audit.
@@ -124,4 +191,38 @@ impl AuditResult {
     pub fn document(&self, locale: &Locale) -> Option<&AuditedDocument> {
         self.documents.get(locale)
     }
+
+    /// Returns the translation calls found in the Rust sources, with their constants
+    /// resolved, each with its location; ordered by file, then as found in the file.
+    pub fn macro_calls(&self) -> impl Iterator<Item = &(SourceLocation, MacroCall)> {
+        self.macro_calls.iter()
+    }
+}
+
+/// Serialized with a `schema_version`, the audit outcome, the workspace's canonical and
+/// primary locales, each document with its `DocumentRole`, and all issues.
+///
+/// Documents are ordered by locale and issues by kind, locations, subject and message, so
+/// that the output is stable between runs.
+impl Serialize for AuditResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut documents = Vec::from_iter(self.documents.values());
+        documents.sort_by_key(|document| document.locale().to_string());
+
+        let mut issues = Vec::from_iter(self.issues.iter());
+        issues.sort_by(|a, b| {
+            (a.kind(), a.subject(), a.message()).cmp(&(b.kind(), b.subject(), b.message()))
+        });
+
+        let primaries = Vec::from_iter(self.workspace.primary_locales());
+
+        let mut state = serializer.serialize_struct("AuditResult", 6)?;
+        state.serialize_field("schema_version", &Self::SCHEMA_VERSION)?;
+        state.serialize_field("ok", &self.is_ok())?;
+        state.serialize_field("canonical", self.canonical_locale())?;
+        state.serialize_field("primaries", &primaries)?;
+        state.serialize_field("documents", &documents)?;
+        state.serialize_field("issues", &issues)?;
+        state.end()
+    }
 }
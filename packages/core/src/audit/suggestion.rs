@@ -0,0 +1,82 @@
+/// The most suggestions offered for a single identifier.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Returns up to three of the candidate identifiers nearest to an undefined identifier,
+/// e.g. `login-button` for `login-buton`, nearest first.
+///
+/// A candidate is near if its edit distance (counting a transposition as a single edit) is
+/// at most a third of the identifier's length, or one more than that if the two share their
+/// first word, e.g. `login` in `login-buton` and `login-button-ok`. Messages are only
+/// suggested for messages, and terms for terms.
+pub(crate) fn suggestions<'a, I>(identifier: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let threshold = (identifier.chars().count() / 3).max(1);
+
+    let mut near = Vec::from_iter(candidates.into_iter().filter_map(|candidate| {
+        if candidate == identifier || candidate.starts_with('-') != identifier.starts_with('-') {
+            return None;
+        }
+
+        let distance = strsim::osa_distance(identifier, candidate);
+        let same_prefix = first_word(candidate) == first_word(identifier);
+        let tolerance = if same_prefix {
+            threshold + 1
+        } else {
+            threshold
+        };
+
+        (distance <= tolerance).then_some((distance, !same_prefix, candidate))
+    }));
+
+    near.sort();
+    near.dedup();
+
+    Vec::from_iter(
+        near.into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, _, candidate)| candidate.to_string()),
+    )
+}
+
+/// The first word of an identifier, e.g. `login` for `login-button.title` or `-brand-name`.
+fn first_word(identifier: &str) -> &str {
+    identifier
+        .trim_start_matches('-')
+        .split(['-', '_', '.'])
+        .next()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nearest_identifiers_are_suggested_first() {
+        let candidates = [
+            "login-button",
+            "login-buttons",
+            "logon-button",
+            "login-button-text",
+            "greeting",
+            "-login-button",
+        ];
+
+        assert_eq!(
+            suggestions("login-buton", candidates),
+            ["login-button", "login-buttons", "logon-button"]
+        );
+        assert_eq!(
+            suggestions("login-buton", ["login-button-ok", "logon-button-ok"]),
+            ["login-button-ok"]
+        );
+        assert!(suggestions("login-buton", ["login-link", "login-button-text"]).is_empty());
+        assert_eq!(
+            suggestions("-brnad-name", ["-brand-name", "brand-name", "-brand"]),
+            ["-brand-name"]
+        );
+        assert!(suggestions("farewell", candidates).is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+use std::{collections::HashSet, ops::Range};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    audit::{AuditIssue, Kind, Subject},
+    domain::{Locale, SourceLocation},
+};
+
+/// The extent of the sources to which a `Suppression` applies.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum Scope {
+    /// The whole file containing the directive.
+    File,
+    /// The lines of the Fluent entry, or Rust item or statement, following the directive.
+    Lines(Range<usize>),
+}
+
+/// A directive, written in a source file, that silences issues of the given kinds.
+///
+/// Kinds are named by `Kind::id`, or one of its aliases, in either `snake_case` or
+/// `kebab-case`; a directive naming no kinds silences all kinds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppression {
+    directive: SourceLocation,
+    kinds: Vec<String>,
+    scope: Scope,
+}
+
+impl Suppression {
+    /// A suppression applying to the entry, item or statement spanning the given lines.
+    pub fn lines(directive: SourceLocation, kinds: Vec<String>, lines: Range<usize>) -> Self {
+        let scope = Scope::Lines(lines);
+        Self {
+            directive,
+            kinds,
+            scope,
+        }
+    }
+
+    /// A suppression applying to every line of the directive's file.
+    pub fn file(directive: SourceLocation, kinds: Vec<String>) -> Self {
+        let scope = Scope::File;
+        Self {
+            directive,
+            kinds,
+            scope,
+        }
+    }
+
+    /// Parses the comma separated kinds following a directive, e.g. `a, b-c`.
+    pub fn parse_kinds(kinds: Option<&str>) -> Vec<String> {
+        kinds
+            .into_iter()
+            .flat_map(|kinds| kinds.split(','))
+            .map(str::trim)
+            .filter(|kind| !kind.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    fn covers(&self, location: &SourceLocation) -> bool {
+        location.path() == self.directive.path()
+            && match &self.scope {
+                Scope::File => true,
+                Scope::Lines(lines) => lines.contains(&location.line()),
+            }
+    }
+
+    fn names(kind: &str, target: &Kind) -> bool {
+        kind.replace('-', "_")
+            .parse::<Kind>()
+            .is_ok_and(|kind| kind == *target)
+    }
+
+    /// Returns the kind, as written, that silences the issue; `Some(None)` if the
+    /// directive silences all kinds, or `None` if the issue is not silenced.
+    ///
+    /// The directive of a Fluent file, of the given `locale`, silences only the issues of
+    /// that locale, and not, e.g., the missing translations of other locales located at a
+    /// canonical entry.
+    fn silencing(&self, locale: Option<&Locale>, issue: &AuditIssue) -> Option<Option<&str>> {
+        let issue_locale = match issue.subject() {
+            Subject::Locale(locale) | Subject::Entry(locale, _) => Some(locale),
+            _ => None,
+        };
+        let is_own = match (locale, issue_locale) {
+            (Some(locale), Some(issue_locale)) => locale == issue_locale,
+            _ => true,
+        };
+
+        if !is_own || !issue.locations().iter().any(|l| self.covers(l)) {
+            None
+        } else if self.kinds.is_empty() {
+            Some(None)
+        } else {
+            self.kinds
+                .iter()
+                .find(|kind| Self::names(kind, issue.kind()))
+                .map(|kind| Some(kind.as_str()))
+        }
+    }
+}
+
+/// Removes the issues silenced by any of the suppressions, each with the locale of the
+/// Fluent document in which it is written, if any, and adds an issue for each
+/// suppression, or suppressed kind, that silenced nothing.
+///
+/// Unused suppression issues cannot themselves be suppressed.
+pub fn apply_suppressions(
+    suppressions: &[(Option<&Locale>, &Suppression)],
+    issues: Vec<AuditIssue>,
+) -> Vec<AuditIssue> {
+    let mut used = HashSet::new();
+
+    let mut issues = issues
+        .into_iter()
+        .filter(|issue| {
+            let silenced_by = suppressions
+                .iter()
+                .enumerate()
+                .filter_map(|(index, (locale, s))| {
+                    s.silencing(*locale, issue).map(|kind| (index, kind))
+                })
+                .collect::<Vec<_>>();
+            let is_silenced = !silenced_by.is_empty();
+            used.extend(
+                silenced_by
+                    .into_iter()
+                    .map(|(i, k)| (i, k.map(String::from))),
+            );
+            !is_silenced
+        })
+        .collect::<Vec<_>>();
+
+    suppressions
+        .iter()
+        .enumerate()
+        .for_each(|(index, (_, suppression))| {
+            if suppression.kinds.is_empty() {
+                if !used.contains(&(index, None)) {
+                    issues.push(AuditIssue::unused_suppression(&suppression.directive, None));
+                }
+            } else {
+                suppression
+                    .kinds
+                    .iter()
+                    .filter(|kind| !used.contains(&(index, Some(kind.to_string()))))
+                    .for_each(|kind| {
+                        issues.push(AuditIssue::unused_suppression(
+                            &suppression.directive,
+                            Some(kind),
+                        ))
+                    });
+            }
+        });
+
+    issues
+}
@@ -1,9 +1,12 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::*;
 
 use crate::{
-    config::config_inclusion_style::ConfigInclusionStyle, domain::Locale, error::LingoraError,
+    audit::Kind,
+    config::{config_inclusion_style::ConfigInclusionStyle, severity::Severity},
+    domain::Locale,
+    error::LingoraError,
 };
 
 /// Core command-line arguments shared between `lingora-cli` and `lingora-tui`.
@@ -15,7 +18,7 @@ use crate::{
 /// - Supplement or override the translation files and locales defined by the configuration.
 /// - Enable Rust source scanning for `dioxus_i18n` macro usage
 /// - Control how generated `I18nConfig` code includes locales
-#[derive(Debug, Parser)]
+#[derive(Clone, Debug, Parser)]
 pub struct CoreArgs {
     /// Config file.
     /// The config file contains attributes, most of which can also be overridden
@@ -45,6 +48,13 @@ pub struct CoreArgs {
     #[arg(long, value_delimiter = ',')]
     pub(crate) primaries: Vec<Locale>,
 
+    /// Cache file for the parsed translation and rust source files.
+    /// If provided, the files are parsed only when they have changed since
+    /// the previous run, which is otherwise restored from the cache.
+    /// Overrides the `cache` defined in the Lingora.toml file.
+    #[clap(long, default_value = None)]
+    pub(crate) cache: Option<PathBuf>,
+
     /// The paths for rust source files that may use dioxus_i18n macros.
     /// If provided Lingora will analyse the source files for usage of the
     /// `dioxus_i18n::t!`, `te!` and `tid!` macros. If parsable (const str)
@@ -57,6 +67,45 @@ pub struct CoreArgs {
     /// during rendering of the config.rs code.
     #[clap(long, default_value = None)]
     pub(crate) config_inclusion: Option<ConfigInclusionStyle>,
+
+    /// Override the severity of a kind of issue, as `<kind>=<error|warning|info|off>`,
+    /// e.g. `--severity=redundant_translation=warning`.
+    /// Overrides are applied after those in the Lingora.toml `[severity]` table.
+    #[clap(long = "severity", value_delimiter = ',', value_parser = parse_severity)]
+    pub(crate) severities: Vec<(Kind, Severity)>,
+}
+
+impl CoreArgs {
+    /// Returns the configuration file to be read; the one requested, or otherwise
+    /// `Lingora.toml` in the current working directory, if it exists.
+    pub fn config_file(&self) -> Option<PathBuf> {
+        self.config_file_in(Path::new(""))
+    }
+
+    /// Returns the configuration file to be read for the workspace at `root`; the one
+    /// requested, taken from `root` if it is relative, or otherwise `Lingora.toml` in
+    /// `root`, if it exists.
+    pub fn config_file_in(&self, root: &Path) -> Option<PathBuf> {
+        let default_toml_path = root.join("Lingora.toml");
+
+        self.config
+            .as_ref()
+            .map(|config| root.join(config))
+            .or_else(|| {
+                std::fs::exists(&default_toml_path)
+                    .unwrap_or(false)
+                    .then_some(default_toml_path)
+            })
+    }
+}
+
+fn parse_severity(s: &str) -> Result<(Kind, Severity), String> {
+    let (kind, severity) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected <kind>=<severity>, found '{s}'"))?;
+    let kind = kind.parse::<Kind>().map_err(|e| e.to_string())?;
+    let severity = Severity::from_str(severity, true)?;
+    Ok((kind, severity))
 }
 
 impl std::str::FromStr for CoreArgs {
@@ -140,4 +189,30 @@ mod test {
             ]
         )
     }
+
+    #[test]
+    fn no_severity_overrides_by_default() {
+        let args = CoreArgs::from_str("").unwrap();
+        assert!(args.severities.is_empty())
+    }
+
+    #[test]
+    fn user_can_provide_severity_overrides() {
+        let args =
+            CoreArgs::from_str("app_name --severity=missing_translation=warning,parse_error=off")
+                .unwrap();
+        assert_eq!(
+            args.severities,
+            [
+                (Kind::MissingTranslation, Severity::Warning),
+                (Kind::ParseError, Severity::Off)
+            ]
+        )
+    }
+
+    #[test]
+    fn user_cannot_provide_unknown_severity_override() {
+        assert!(CoreArgs::from_str("app_name --severity=missing=warning").is_err());
+        assert!(CoreArgs::from_str("app_name --severity=parse_error=fatal").is_err());
+    }
 }
@@ -1,7 +1,10 @@
 mod args;
 mod config_inclusion_style;
+mod severity;
 mod toml;
 
 pub use args::CoreArgs;
 pub use config_inclusion_style::ConfigInclusionStyle;
+pub use severity::Severity;
 pub use toml::LingoraToml;
+pub(crate) use toml::{AuditSettings, RustScanSettings, SeveritySettings};
@@ -0,0 +1,34 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// How an issue of a given `Kind` is treated.
+///
+/// Only `Error` issues cause an audit to fail; `Warning` and `Info` issues are reported
+/// but tolerated, and `Off` issues are not reported at all.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ValueEnum,
+)]
+#[clap(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The issue is reported and fails the audit.
+    #[default]
+    Error,
+    /// The issue is reported, but does not fail the audit.
+    Warning,
+    /// The issue is reported for information only.
+    Info,
+    /// The issue is not reported.
+    Off,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => "error".fmt(f),
+            Severity::Warning => "warning".fmt(f),
+            Severity::Info => "info".fmt(f),
+            Severity::Off => "off".fmt(f),
+        }
+    }
+}
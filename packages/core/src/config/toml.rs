@@ -1,9 +1,14 @@
-use std::path::{Path, PathBuf};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
+use path_clean::PathClean;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    config::{args::CoreArgs, config_inclusion_style::ConfigInclusionStyle},
+    audit::Kind,
+    config::{args::CoreArgs, config_inclusion_style::ConfigInclusionStyle, severity::Severity},
     domain::Locale,
     error::LingoraError,
 };
@@ -14,6 +19,8 @@ pub(crate) struct EngineSettings {
     pub(crate) fluent_sources: Vec<PathBuf>,
     pub(crate) canonical: Locale,
     pub(crate) primaries: Vec<Locale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cache: Option<PathBuf>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -23,12 +30,125 @@ pub(crate) struct DioxusI18nSettings {
     pub(crate) config_inclusion: ConfigInclusionStyle,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct AuditSettings {
+    pub(crate) unused_identifiers: bool,
+    pub(crate) allowed_untranslated_copies: Vec<String>,
+    pub(crate) hardcoded_text: bool,
+    pub(crate) allowed_hardcoded_text: Vec<String>,
+}
+
+/// The macros, functions and methods whose calls name a Fluent identifier, and where that
+/// identifier appears among their arguments.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct RustScanSettings {
+    pub(crate) macros: Vec<TranslationCall>,
+    pub(crate) functions: Vec<TranslationCall>,
+}
+
+impl RustScanSettings {
+    /// Returns the zero-based position of the identifier argument if the macro `path`,
+    /// e.g. `dioxus_i18n::t`, is a translation macro.
+    pub(crate) fn macro_argument(&self, path: &str) -> Option<usize> {
+        Self::argument(&self.macros, path)
+    }
+
+    /// Returns the zero-based position of the identifier argument if the function `path`,
+    /// e.g. `i18n::lookup`, is a translation function.
+    pub(crate) fn function_argument(&self, path: &str) -> Option<usize> {
+        Self::argument(&self.functions, path)
+    }
+
+    /// Returns the zero-based position of the identifier argument, not counting the
+    /// receiver, if the method `name` is a translation function. The receiver's type is
+    /// not known, so only functions configured without a path match methods.
+    pub(crate) fn method_argument(&self, name: &str) -> Option<usize> {
+        self.functions
+            .iter()
+            .find(|f| f.name == name)
+            .map(|f| f.argument)
+    }
+
+    /// A configured name without a path matches the call however it is qualified; a
+    /// path-qualified name matches calls written with the trailing segments of its path,
+    /// or with its last segment alone, as brought into scope by a `use`.
+    fn argument(calls: &[TranslationCall], path: &str) -> Option<usize> {
+        let path = path.trim_start_matches("::");
+
+        calls
+            .iter()
+            .find(|c| c.name.trim_start_matches("::") == path)
+            .or_else(|| {
+                calls
+                    .iter()
+                    .find(|c| path.ends_with(&format!("::{}", c.name.trim_start_matches("::"))))
+            })
+            .or_else(|| {
+                calls
+                    .iter()
+                    .find(|c| !path.contains("::") && c.name.rsplit("::").next() == Some(path))
+            })
+            .map(|c| c.argument)
+    }
+}
+
+impl Default for RustScanSettings {
+    fn default() -> Self {
+        let macros = ["t", "te", "tid"]
+            .into_iter()
+            .map(|name| TranslationCall {
+                name: name.into(),
+                argument: 0,
+            })
+            .collect();
+
+        Self {
+            macros,
+            functions: Vec::default(),
+        }
+    }
+}
+
+/// A translation macro, function or method, e.g. `{ name = "fl", argument = 1 }` for
+/// `fl!(loader, "key")`, or `{ name = "lookup", argument = 1 }` for
+/// `LOCALES.lookup(&langid, "key")`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct TranslationCall {
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) argument: usize,
+}
+
+/// The `Severity` of each `Kind` of issue; kinds not listed have their default severity.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct SeveritySettings(BTreeMap<Kind, Severity>);
+
+impl SeveritySettings {
+    pub(crate) fn severity(&self, kind: &Kind) -> Severity {
+        self.0
+            .get(kind)
+            .copied()
+            .unwrap_or_else(|| kind.default_severity())
+    }
+
+    fn set(&mut self, kind: &Kind, severity: Severity) {
+        self.0.insert(kind.clone(), severity);
+    }
+}
+
 /// Top-level deserialized structure of a `Lingora.toml` configuration file.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct LingoraToml {
     pub(crate) lingora: EngineSettings,
     pub(crate) dioxus_i18n: DioxusI18nSettings,
+    pub(crate) audit: AuditSettings,
+    pub(crate) rust_scan: RustScanSettings,
+    pub(crate) severity: SeveritySettings,
 }
 
 impl std::str::FromStr for LingoraToml {
@@ -54,14 +174,33 @@ impl TryFrom<&CoreArgs> for LingoraToml {
     type Error = LingoraError;
 
     fn try_from(args: &CoreArgs) -> Result<Self, Self::Error> {
-        let default_toml_path = Path::new("Lingora.toml");
-
-        let mut toml = if let Some(requested_toml_path) = &args.config {
-            Self::try_from(requested_toml_path.as_path())
-        } else if std::fs::exists(default_toml_path).unwrap_or(false) {
-            Self::try_from(default_toml_path)
-        } else {
-            Ok(Self::default())
+        Self::from_args(args, args.config_file())
+    }
+}
+
+impl LingoraToml {
+    /// Reads the settings for the workspace at `root`, as `LingoraToml::try_from(args)`
+    /// would if run from within `root`, without changing the current directory; relative
+    /// paths, of the configuration file and of the sources and cache, are taken from `root`.
+    pub fn try_from_args_in(args: &CoreArgs, root: &Path) -> Result<Self, LingoraError> {
+        let mut toml = Self::from_args(args, args.config_file_in(root))?;
+
+        let resolve = |path: &mut PathBuf| {
+            if path.is_relative() {
+                *path = root.join(&path).clean();
+            }
+        };
+        toml.lingora.fluent_sources.iter_mut().for_each(resolve);
+        toml.lingora.cache.iter_mut().for_each(resolve);
+        toml.dioxus_i18n.rust_sources.iter_mut().for_each(resolve);
+
+        Ok(toml)
+    }
+
+    fn from_args(args: &CoreArgs, config_file: Option<PathBuf>) -> Result<Self, LingoraError> {
+        let mut toml = match config_file {
+            Some(toml_path) => Self::try_from(toml_path.as_path()),
+            None => Ok(Self::default()),
         }?;
 
         toml.lingora
@@ -74,6 +213,10 @@ impl TryFrom<&CoreArgs> for LingoraToml {
 
         toml.lingora.primaries.append(&mut args.primaries.clone());
 
+        if let Some(cache) = &args.cache {
+            toml.lingora.cache = Some(cache.clone());
+        }
+
         toml.dioxus_i18n
             .rust_sources
             .append(&mut args.rust_sources.clone());
@@ -82,6 +225,10 @@ impl TryFrom<&CoreArgs> for LingoraToml {
             toml.dioxus_i18n.config_inclusion = *style;
         }
 
+        args.severities
+            .iter()
+            .for_each(|(kind, severity)| toml.severity.set(kind, *severity));
+
         Ok(toml)
     }
 }
@@ -99,11 +246,15 @@ impl Default for LingoraToml {
                 fluent_sources,
                 canonical,
                 primaries,
+                cache: None,
             },
             dioxus_i18n: DioxusI18nSettings {
                 rust_sources,
                 config_inclusion,
             },
+            audit: AuditSettings::default(),
+            rust_scan: RustScanSettings::default(),
+            severity: SeveritySettings::default(),
         }
     }
 }
@@ -121,7 +272,11 @@ mod test {
     use std::{path::Path, str::FromStr};
 
     use crate::{
-        config::toml::{ConfigInclusionStyle, LingoraToml},
+        audit::Kind,
+        config::{
+            CoreArgs, Severity,
+            toml::{ConfigInclusionStyle, LingoraToml, RustScanSettings, SeveritySettings},
+        },
         domain::Locale,
         error::LingoraError,
     };
@@ -138,6 +293,9 @@ mod test {
             toml.dioxus_i18n.config_inclusion,
             ConfigInclusionStyle::Auto
         );
+        assert!(!toml.audit.unused_identifiers);
+        assert!(!toml.audit.hardcoded_text);
+        assert_eq!(toml.severity, SeveritySettings::default());
     }
 
     #[test]
@@ -182,6 +340,85 @@ mod test {
         );
     }
 
+    #[test]
+    fn will_load_audit_settings_from_str() {
+        let toml = LingoraToml::from_str(
+            r#"
+[audit]
+unused_identifiers = true
+allowed_untranslated_copies = ["brand-name", "-product"]
+hardcoded_text = true
+allowed_hardcoded_text = ["OK", "Lingora"]
+"#,
+        )
+        .expect("failed to parse toml");
+        assert!(toml.audit.unused_identifiers);
+        assert_eq!(
+            toml.audit.allowed_untranslated_copies,
+            ["brand-name", "-product"]
+        );
+        assert!(toml.audit.hardcoded_text);
+        assert_eq!(toml.audit.allowed_hardcoded_text, ["OK", "Lingora"]);
+    }
+
+    #[test]
+    fn will_load_rust_scan_settings_from_str() {
+        let toml = LingoraToml::from_str(
+            r#"
+[rust_scan]
+macros = [
+    { name = "t" },
+    { name = "i18n_embed_fl::fl", argument = 1 },
+]
+"#,
+        )
+        .expect("failed to parse toml");
+
+        let rust_scan = &toml.rust_scan;
+        assert_eq!(rust_scan.macro_argument("t"), Some(0));
+        assert_eq!(rust_scan.macro_argument("dioxus_i18n::t"), Some(0));
+        assert_eq!(rust_scan.macro_argument("i18n_embed_fl::fl"), Some(1));
+        assert_eq!(rust_scan.macro_argument("::i18n_embed_fl::fl"), Some(1));
+        assert_eq!(rust_scan.macro_argument("fl"), Some(1));
+        assert_eq!(rust_scan.macro_argument("other::fl"), None);
+        assert_eq!(rust_scan.macro_argument("tid"), None);
+    }
+
+    #[test]
+    fn will_load_rust_scan_functions_from_str() {
+        let toml = LingoraToml::from_str(
+            r#"
+[rust_scan]
+functions = [
+    { name = "lookup", argument = 1 },
+    { name = "i18n::translate" },
+]
+"#,
+        )
+        .expect("failed to parse toml");
+
+        let rust_scan = &toml.rust_scan;
+        assert_eq!(rust_scan.macro_argument("t"), Some(0));
+        assert_eq!(rust_scan.method_argument("lookup"), Some(1));
+        assert_eq!(rust_scan.function_argument("Loader::lookup"), Some(1));
+        assert_eq!(
+            rust_scan.function_argument("crate::i18n::translate"),
+            Some(0)
+        );
+        assert_eq!(rust_scan.function_argument("translate"), Some(0));
+        assert_eq!(rust_scan.function_argument("other::translate"), None);
+        assert_eq!(rust_scan.method_argument("translate"), None);
+    }
+
+    #[test]
+    fn will_default_rust_scan_to_dioxus_i18n_macros() {
+        let rust_scan = RustScanSettings::default();
+        assert_eq!(rust_scan.macro_argument("t"), Some(0));
+        assert_eq!(rust_scan.macro_argument("te"), Some(0));
+        assert_eq!(rust_scan.macro_argument("tid"), Some(0));
+        assert_eq!(rust_scan.macro_argument("format"), None);
+    }
+
     #[test]
     fn will_load_from_args_config() {
         use crate::config::args::CoreArgs;
@@ -198,6 +435,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn will_load_from_args_relative_to_a_root() {
+        use crate::config::args::CoreArgs;
+        let root = std::fs::canonicalize("./tests/data/toml").unwrap();
+        let args = CoreArgs::from_str(
+            "app_name --config=Lingora_args.toml --rust-sources=/abs/src --cache=target/cache.json",
+        )
+        .unwrap();
+        let toml = LingoraToml::try_from_args_in(&args, &root).unwrap();
+        assert_eq!(toml.lingora.fluent_sources, [root.join("args/i18n")]);
+        assert_eq!(toml.lingora.canonical, Locale::from_str("de-DE").unwrap());
+        assert_eq!(toml.lingora.cache, Some(root.join("target/cache.json")));
+        assert_eq!(
+            toml.dioxus_i18n.rust_sources,
+            [root.join("args/src"), Path::new("/abs/src").to_path_buf()]
+        );
+    }
+
     #[test]
     fn will_load_from_args_overridden() {
         use crate::config::args::CoreArgs;
@@ -231,4 +486,53 @@ mod test {
             ConfigInclusionStyle::PathBuf
         );
     }
+
+    #[test]
+    fn will_load_severity_settings_from_str() {
+        let toml = LingoraToml::from_str(
+            r#"
+[severity]
+redundant_translation = "warning"
+unused_identifier = "off"
+"#,
+        )
+        .expect("valid toml");
+
+        assert_eq!(
+            toml.severity.severity(&Kind::RedundantTranslation),
+            Severity::Warning
+        );
+        assert_eq!(
+            toml.severity.severity(&Kind::UnusedIdentifier),
+            Severity::Off
+        );
+        assert_eq!(toml.severity.severity(&Kind::ParseError), Severity::Error);
+    }
+
+    #[test]
+    fn will_not_load_unknown_severity_kind() {
+        let error = LingoraToml::from_str(
+            r#"
+[severity]
+not_a_kind = "warning"
+"#,
+        )
+        .expect_err("failed to detect error");
+        assert!(matches!(error, LingoraError::TomlParse(_)));
+    }
+
+    #[test]
+    fn will_override_severity_settings_from_args() {
+        let args = CoreArgs::from_str(
+            "app_name --config=./tests/data/toml/Lingora.toml --severity=parse_error=warning,redundant_translation=off",
+        )
+        .expect("valid args");
+        let toml = LingoraToml::try_from(&args).expect("valid toml");
+
+        assert_eq!(toml.severity.severity(&Kind::ParseError), Severity::Warning);
+        assert_eq!(
+            toml.severity.severity(&Kind::RedundantTranslation),
+            Severity::Off
+        );
+    }
 }
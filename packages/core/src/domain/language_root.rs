@@ -1,4 +1,5 @@
 use icu_locale_core::subtags::{Language, Script};
+use serde::{Serialize, Serializer};
 
 use crate::{domain::Locale, error::LingoraError};
 
@@ -40,6 +41,13 @@ impl std::str::FromStr for LanguageRoot {
     }
 }
 
+/// Serialized in its display form, e.g. `sr-Cyrl`.
+impl Serialize for LanguageRoot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 impl std::fmt::Display for LanguageRoot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
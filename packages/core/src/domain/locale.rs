@@ -43,6 +43,10 @@ impl Locale {
     pub fn has_variants(&self) -> bool {
         !self.0.variants.is_empty()
     }
+
+    pub(crate) fn language_identifier(&self) -> &LanguageIdentifier {
+        &self.0
+    }
 }
 
 impl Default for Locale {
@@ -1,5 +1,7 @@
 mod language_root;
 mod locale;
+mod source_location;
 
 pub use language_root::LanguageRoot;
 pub use locale::{HasLocale, Locale};
+pub use source_location::SourceLocation;
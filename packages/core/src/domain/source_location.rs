@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// A position within a source file, i.e. a `.ftl` or `.rs` file.
+///
+/// Lines and columns are one-based, matching the convention used by compilers and
+/// editors, so the `path:line:column` display form can be followed directly.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct SourceLocation {
+    path: PathBuf,
+    line: usize,
+    column: usize,
+}
+
+impl SourceLocation {
+    /// Creates a location at the given one-based line and column of the file.
+    pub fn new(path: &Path, line: usize, column: usize) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            line,
+            column,
+        }
+    }
+
+    /// Creates a location referring to the start of the file.
+    pub fn start_of(path: &Path) -> Self {
+        Self::new(path, 1, 1)
+    }
+
+    /// Creates a location from a byte offset into the file's content.
+    pub fn from_offset(path: &Path, content: &str, offset: usize) -> Self {
+        let preceding = &content[..offset.min(content.len())];
+        let line = preceding.matches('\n').count() + 1;
+        let line_start = preceding.rfind('\n').map_or(0, |i| i + 1);
+        let column = preceding[line_start..].chars().count() + 1;
+        Self::new(path, line, column)
+    }
+
+    /// The file containing the location.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The one-based line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The one-based column number, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn will_locate_offset_within_content() {
+        let path = Path::new("en.ftl");
+        let content = "a = A\nbb = é B\n";
+
+        let location = SourceLocation::from_offset(path, content, 0);
+        assert_eq!((location.line(), location.column()), (1, 1));
+
+        let location =
+            SourceLocation::from_offset(path, content, content.find('B').expect("valid offset"));
+        assert_eq!((location.line(), location.column()), (2, 8));
+    }
+
+    #[test]
+    fn will_display_as_path_line_column() {
+        let location = SourceLocation::new(Path::new("i18n/en.ftl"), 3, 5);
+        assert_eq!(location.to_string(), "i18n/en.ftl:3:5");
+    }
+}
@@ -11,6 +11,15 @@ pub enum LingoraError {
     #[error(transparent)]
     TomlParse(#[from] toml::de::Error),
 
+    /// Failed to serialize an audit result as JSON.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    /// The Fluent or Rust sources, or the configuration file, could not be watched for
+    /// changes.
+    #[error(transparent)]
+    Watch(#[from] notify::Error),
+
     /// Clap failed to parse command-line arguments.
     #[error(transparent)]
     Args(#[from] clap::Error),
@@ -28,6 +37,15 @@ pub enum LingoraError {
     #[error("invalid locale: {0}")]
     InvalidLocale(String),
 
+    /// The provided name does not identify a kind of audit issue, e.g. in the `[severity]`
+    /// table of `Lingora.toml`.
+    #[error("unknown issue kind: {0}")]
+    UnknownKind(String),
+
+    /// A baseline file was written by an incompatible version of lingora.
+    #[error("unsupported baseline schema version: {0}")]
+    UnsupportedBaseline(u32),
+
     /// A file path was given as a Fluent translation file, but it doesn't match expected
     /// naming conventions or location rules (e.g. wrong extension, not under a locale dir).
     #[error("invalid fluent file path: {0}")]
@@ -1,12 +1,13 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     sync::Arc,
 };
 
 use fluent4rs::{ast::*, prelude::*};
+use serde::Serialize;
 
 use crate::fluent::{
-    QualifiedIdentifier,
+    PluralSelect, PluralType, QualifiedIdentifier,
     path::{Path, PathSegment},
 };
 
@@ -44,15 +45,215 @@ impl PathStack {
     }
 }
 
+/// The kind of selector of a select expression, as far as plural categories are concerned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Selector {
+    Number(PluralType),
+    Variable,
+    Other,
+}
+
+/// A select expression whose variants are being visited, at the walker's `depth`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct OpenSelect {
+    depth: usize,
+    root: Path,
+    selector: Selector,
+    keys: Vec<String>,
+    category_variants: Vec<Path>,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Signature {
     has_value: bool,
     paths: HashSet<Path>,
+    plural_variants: HashSet<Path>,
+}
+
+impl Signature {
+    /// Returns the paths to compare with another locale's, i.e. all but those of the
+    /// plural category variants and their contents, as each locale's plural rules have
+    /// their own categories.
+    fn compared_paths(&self) -> HashSet<&Path> {
+        self.paths
+            .iter()
+            .filter(|path| {
+                !self
+                    .plural_variants
+                    .iter()
+                    .any(|variant| path.segments().starts_with(variant.segments()))
+            })
+            .collect()
+    }
+
+    /// Returns how the `target` signature, e.g. a translation's, differs from this, its
+    /// base, signature.
+    pub fn diff(&self, target: &Signature) -> SignatureDiff {
+        let mut diff = SignatureDiff {
+            value: match (self.has_value, target.has_value) {
+                (true, false) => Some(ValueDiff::Missing),
+                (false, true) => Some(ValueDiff::Unexpected),
+                _ => None,
+            },
+            ..Default::default()
+        };
+
+        let (paths, target_paths) = (self.compared_paths(), target.compared_paths());
+
+        let mut missing = Vec::from_iter(paths.difference(&target_paths).copied());
+        missing.sort();
+        missing
+            .into_iter()
+            .for_each(|path| match SignatureDiff::describe(path) {
+                Some(SignatureItem::Variable(name)) => diff.missing_variables.push(name),
+                Some(SignatureItem::Attribute(name)) => diff.missing_attributes.push(name),
+                Some(SignatureItem::Variant(name)) => diff.missing_variants.push(name),
+                None => {}
+            });
+
+        let mut unexpected = Vec::from_iter(target_paths.difference(&paths).copied());
+        unexpected.sort();
+        unexpected
+            .into_iter()
+            .for_each(|path| match SignatureDiff::describe(path) {
+                Some(SignatureItem::Variable(name)) => diff.unexpected_variables.push(name),
+                Some(SignatureItem::Attribute(name)) => diff.unexpected_attributes.push(name),
+                Some(SignatureItem::Variant(name)) => diff.unexpected_variants.push(name),
+                None => {}
+            });
+
+        diff
+    }
+}
+
+/// Whether a target entry has a value, i.e. a pattern, where its base does not, or vice versa.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueDiff {
+    /// The base entry has a value, but the target does not.
+    Missing,
+    /// The target entry has a value, but the base does not.
+    Unexpected,
+}
+
+/// The differences between an entry's signature in a base document, e.g. the canonical,
+/// and in a target document, e.g. a primary.
+///
+/// Items of the base that the target lacks are _missing_; those of the target that the
+/// base lacks are _unexpected_. Variables are named with their `$`, attributes with their
+/// `.`, and variants by their path within the entry, e.g. `.label / *[other]`, where `*`
+/// marks the default variant.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SignatureDiff {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<ValueDiff>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_variables: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unexpected_variables: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_attributes: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unexpected_attributes: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing_variants: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unexpected_variants: Vec<String>,
+}
+
+enum SignatureItem {
+    Variable(String),
+    Attribute(String),
+    Variant(String),
+}
+
+impl SignatureDiff {
+    fn describe(path: &Path) -> Option<SignatureItem> {
+        let name = |segment: &PathSegment| match segment {
+            PathSegment::DefaultVariant(name) => format!("*{name}"),
+            PathSegment::Message(name)
+            | PathSegment::Term(name)
+            | PathSegment::Attribute(name)
+            | PathSegment::Variant(name)
+            | PathSegment::Variable(name) => name.clone(),
+        };
+
+        match path.segments() {
+            [_, PathSegment::Variable(variable)] => Some(SignatureItem::Variable(variable.clone())),
+            [_, PathSegment::Attribute(attribute)] => {
+                Some(SignatureItem::Attribute(attribute.clone()))
+            }
+            [
+                _,
+                within @ ..,
+                PathSegment::Variant(_) | PathSegment::DefaultVariant(_),
+            ] => {
+                let names = Vec::from_iter(within.iter().map(name));
+                let variant = name(path.segments().last()?);
+                Some(SignatureItem::Variant(
+                    names
+                        .into_iter()
+                        .chain([variant])
+                        .collect::<Vec<_>>()
+                        .join(" / "),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the signatures do not differ.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns each difference as a short, human-readable phrase, e.g.
+    /// `missing variable $count`, ordered value, variables, attributes then variants.
+    pub fn changes(&self) -> Vec<String> {
+        let value = self.value.map(|value| match value {
+            ValueDiff::Missing => String::from("missing value"),
+            ValueDiff::Unexpected => String::from("unexpected value"),
+        });
+
+        let items = |missing: &[String], unexpected: &[String], item: &str| {
+            missing
+                .iter()
+                .map(|name| format!("missing {item} {name}"))
+                .chain(
+                    unexpected
+                        .iter()
+                        .map(|name| format!("unexpected {item} {name}")),
+                )
+                .collect::<Vec<_>>()
+        };
+
+        value
+            .into_iter()
+            .chain(items(
+                &self.missing_variables,
+                &self.unexpected_variables,
+                "variable",
+            ))
+            .chain(items(
+                &self.missing_attributes,
+                &self.unexpected_attributes,
+                "attribute",
+            ))
+            .chain(items(
+                &self.missing_variants,
+                &self.unexpected_variants,
+                "variant",
+            ))
+            .collect()
+    }
 }
 
 type EntriesById = HashMap<Path, Vec<Arc<Entry>>>;
 type Signatures = HashMap<Path, Signature>;
+type VariablesById = HashMap<Path, BTreeSet<String>>;
 type References = Vec<Path>;
+type ReferrersById = HashMap<Path, Vec<Path>>;
+type PluralSelectsById = HashMap<Path, Vec<PluralSelect>>;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Definitions {
@@ -60,8 +261,15 @@ pub struct Definitions {
     path_stack: PathStack,
     entry_by_id: EntriesById,
     signatures: Signatures,
+    value_variables: VariablesById,
+    attribute_variables: VariablesById,
     reference_stack: PathStack,
     references: References,
+    referrers: ReferrersById,
+    texts: HashSet<Path>,
+    depth: usize,
+    open_selects: Vec<OpenSelect>,
+    plural_selects: PluralSelectsById,
 }
 
 impl Definitions {
@@ -111,10 +319,79 @@ impl Definitions {
         signature.paths.insert(path);
     }
 
+    fn record_reference(&mut self, segment: PathSegment) {
+        self.reference_stack.push(segment);
+        let reference = Path::from(self.reference_stack.current());
+        let referrer = Path::from(self.path_stack.root());
+
+        let referrers = self.referrers.entry(reference.clone()).or_default();
+        if !referrers.contains(&referrer) {
+            referrers.push(referrer);
+        }
+
+        self.references.push(reference);
+    }
+
+    fn record_variant_key(&mut self, key: &VariantKey) {
+        // Variants are walked within their variant list, within the select expression...
+        let depth = self.depth;
+        if let Some(select) = self.open_selects.last_mut()
+            && select.depth + 2 == depth
+            && let VariantKey::Identifier(identifier) = key
+        {
+            let key = identifier.to_string();
+            if PluralSelect::is_category(&key) {
+                select
+                    .category_variants
+                    .push(Path::from(self.path_stack.current()));
+            }
+            select.keys.push(key);
+        }
+    }
+
+    fn close_selects(&mut self) {
+        while let Some(select) = self.open_selects.pop_if(|select| select.depth > self.depth) {
+            let plural_select = match select.selector {
+                Selector::Number(plural_type) => {
+                    Some(PluralSelect::number(plural_type, select.keys))
+                }
+                Selector::Variable => PluralSelect::variable(select.keys),
+                Selector::Other => None,
+            };
+
+            if let Some(plural_select) = plural_select {
+                self.signatures
+                    .entry(select.root.clone())
+                    .or_default()
+                    .plural_variants
+                    .extend(select.category_variants);
+                self.plural_selects
+                    .entry(select.root)
+                    .or_default()
+                    .push(plural_select);
+            }
+        }
+    }
+
     pub fn signature(&self, identifier: &QualifiedIdentifier) -> Option<&Signature> {
         self.signatures.get(identifier.path())
     }
 
+    pub fn variables(&self, identifier: &QualifiedIdentifier) -> Option<Vec<&str>> {
+        match identifier.path().segments() {
+            [_, PathSegment::Attribute(_)] => self
+                .attribute_variables
+                .get(identifier.path())
+                .map(|variables| Vec::from_iter(variables.iter().map(String::as_str))),
+            _ => self.signature(identifier).map(|_| {
+                self.value_variables
+                    .get(identifier.path())
+                    .map(|variables| Vec::from_iter(variables.iter().map(String::as_str)))
+                    .unwrap_or_default()
+            }),
+        }
+    }
+
     pub fn entry_identifiers(&self) -> impl Iterator<Item = QualifiedIdentifier> {
         self.signatures.keys().map(QualifiedIdentifier::from)
     }
@@ -126,16 +403,47 @@ impl Definitions {
         })
     }
 
+    pub fn public_identifiers(&self) -> impl Iterator<Item = QualifiedIdentifier> {
+        self.signatures
+            .values()
+            .flat_map(|signature| signature.paths.iter())
+            .filter(|path| path.is_public())
+            .map(QualifiedIdentifier::from)
+    }
+
     pub fn references(&self) -> impl Iterator<Item = QualifiedIdentifier> {
         self.references.iter().map(QualifiedIdentifier::from)
     }
 
+    pub fn referrers(
+        &self,
+        identifier: &QualifiedIdentifier,
+    ) -> impl Iterator<Item = QualifiedIdentifier> {
+        self.referrers
+            .get(identifier.path())
+            .into_iter()
+            .flat_map(|referrers| referrers.iter())
+            .map(QualifiedIdentifier::from)
+    }
+
     pub fn invalid_references(&self) -> Vec<QualifiedIdentifier> {
         let defined = self.entry_identifiers().collect::<HashSet<_>>();
         let references = self.references().collect::<HashSet<_>>();
         references.difference(&defined).cloned().collect()
     }
 
+    pub fn has_text(&self, identifier: &QualifiedIdentifier) -> bool {
+        self.texts.contains(identifier.path())
+    }
+
+    pub fn plural_selects(&self) -> impl Iterator<Item = (QualifiedIdentifier, &PluralSelect)> {
+        self.plural_selects.iter().flat_map(|(path, selects)| {
+            selects
+                .iter()
+                .map(move |select| (QualifiedIdentifier::from(path), select))
+        })
+    }
+
     pub fn entries(&self, identifier: &QualifiedIdentifier) -> impl Iterator<Item = &Entry> {
         self.entry_by_id
             .get(identifier.path())
@@ -149,11 +457,14 @@ impl Visitor for Definitions {
     fn enter(&mut self) {
         self.path_stack.enter();
         self.reference_stack.enter();
+        self.depth += 1;
     }
 
     fn exit(&mut self) {
         self.path_stack.exit();
         self.reference_stack.exit();
+        self.depth -= 1;
+        self.close_selects();
     }
 
     fn visit_entry(&mut self, entry: &Entry) {
@@ -172,46 +483,98 @@ impl Visitor for Definitions {
         self.update_signature_has_value(true);
     }
 
+    fn visit_pattern_element(&mut self, element: &PatternElement) {
+        let text = match element {
+            PatternElement::InlineText(text) => text.to_string(),
+            PatternElement::BlockText(text) => text.to_string(),
+            _ => String::new(),
+        };
+
+        if text.chars().any(char::is_alphabetic) {
+            self.texts.insert(Path::from(self.path_stack.root()));
+        }
+    }
+
     fn visit_attribute(&mut self, attribute: &Attribute) {
         let segment = PathSegment::Attribute(attribute.identifier_name());
         self.record_identifier(&segment);
+        self.attribute_variables
+            .entry(Path::from(self.path_stack.current()))
+            .or_default();
+    }
+
+    fn visit_select_expression(&mut self, expression: &SelectExpression) {
+        let selector = match expression.inline_expression() {
+            InlineExpression::VariableReference(_) => Selector::Variable,
+            InlineExpression::FunctionReference(function)
+                if function.identifier_name() == "NUMBER" =>
+            {
+                let is_ordinal = function
+                    .call_arguments()
+                    .arguments()
+                    .iter()
+                    .any(|argument| {
+                        matches!(argument, Argument::NamedArgument(argument)
+                        if argument.identifier().to_string() == "type"
+                            && argument.to_string().ends_with("\"ordinal\""))
+                    });
+                let plural_type = if is_ordinal {
+                    PluralType::Ordinal
+                } else {
+                    PluralType::Cardinal
+                };
+                Selector::Number(plural_type)
+            }
+            _ => Selector::Other,
+        };
+
+        self.open_selects.push(OpenSelect {
+            depth: self.depth,
+            root: Path::from(self.path_stack.root()),
+            selector,
+            keys: Vec::new(),
+            category_variants: Vec::new(),
+        });
     }
 
     fn visit_variant(&mut self, variant: &Variant) {
         let segment = PathSegment::Variant(format!("[{}]", variant.variant_key()));
         self.record_identifier(&segment);
+        self.record_variant_key(variant.variant_key());
     }
 
     fn visit_default_variant(&mut self, variant: &DefaultVariant) {
         let segment = PathSegment::DefaultVariant(format!("[{}]", variant.variant_key()));
         self.record_identifier(&segment);
+        self.record_variant_key(variant.variant_key());
     }
 
     fn visit_message_reference(&mut self, reference: &MessageReference) {
         let reference = reference.identifier_name();
-        let segment = PathSegment::Message(reference);
-        self.reference_stack.push(segment);
-        self.references
-            .push(Path::from(self.reference_stack.current()));
+        self.record_reference(PathSegment::Message(reference));
     }
 
     fn visit_term_reference(&mut self, reference: &TermReference) {
         let reference = reference.identifier_name();
-        let segment = PathSegment::Term(reference);
-        self.reference_stack.push(segment);
-        self.references
-            .push(Path::from(self.reference_stack.current()));
+        self.record_reference(PathSegment::Term(reference));
     }
 
     fn visit_attribute_accessor(&mut self, accessor: &AttributeAccessor) {
         let accessor = accessor.identifier_name();
-        let segment = PathSegment::Attribute(accessor);
-        self.reference_stack.push(segment);
-        self.references
-            .push(Path::from(self.reference_stack.current()));
+        self.record_reference(PathSegment::Attribute(accessor));
     }
 
     fn visit_variable_reference(&mut self, reference: &VariableReference) {
+        let variables = match self.path_stack.current() {
+            [root, attribute @ PathSegment::Attribute(_), ..] => self
+                .attribute_variables
+                .entry(Path::from([root.clone(), attribute.clone()].as_slice())),
+            _ => self
+                .value_variables
+                .entry(Path::from(self.path_stack.root())),
+        };
+        variables.or_default().insert(reference.identifier_name());
+
         let mut path = Vec::from(self.path_stack.root());
         path.push(PathSegment::Variable(reference.identifier_name()));
         self.append_signature_path(Path::from(path.as_slice()));
@@ -1,10 +1,15 @@
-use std::sync::OnceLock;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
 
 use fluent4rs::{ast::*, prelude::Walker};
 
 use crate::{
-    domain::{HasLocale, Locale},
-    fluent::{Definitions, ParsedFluentFile, QualifiedIdentifier, Signature},
+    audit::Suppression,
+    domain::{HasLocale, Locale, SourceLocation},
+    fluent::{Definitions, ParsedFluentFile, PluralSelect, QualifiedIdentifier, Signature},
 };
 
 /// A normalized, representation of all Fluent translations for **one locale**.
@@ -15,6 +20,10 @@ use crate::{
 pub struct FluentDocument {
     locale: Locale,
     resource: Resource,
+    paths: Vec<PathBuf>,
+    locations: HashMap<QualifiedIdentifier, Vec<SourceLocation>>,
+    comments: HashMap<QualifiedIdentifier, Vec<String>>,
+    suppressions: Vec<Suppression>,
     analysis: OnceLock<Definitions>,
 }
 
@@ -22,20 +31,47 @@ impl FluentDocument {
     /// Constructs a `FluentDocument` by merging entries from all `ParsedFluentFile`s
     /// that match the given locale.
     pub fn from_parsed_files(locale: &Locale, files: &[ParsedFluentFile]) -> Self {
+        let files = Vec::from_iter(files.iter().filter(|f| f.locale() == locale));
+
         let entries = files
             .iter()
-            .filter(|f| f.locale() == locale)
             .filter_map(|f| f.resource())
             .flat_map(|r| r.entries().into_iter().cloned())
             .collect::<Vec<_>>();
 
         let locale = locale.clone();
         let resource = Resource::from(entries);
+        let paths = Vec::from_iter(files.iter().map(|f| f.path().to_path_buf()));
+        let locations = files
+            .iter()
+            .filter(|f| f.resource().is_some())
+            .flat_map(|f| f.locations())
+            .fold(HashMap::new(), |mut acc, (identifier, location)| {
+                acc.entry(identifier.clone())
+                    .or_insert_with(Vec::new)
+                    .push(location.clone());
+                acc
+            });
+        let comments = files
+            .iter()
+            .filter(|f| f.resource().is_some())
+            .flat_map(|f| f.comments())
+            .fold(HashMap::new(), |mut acc, (identifier, comments)| {
+                acc.entry(identifier.clone())
+                    .or_insert_with(Vec::new)
+                    .extend(comments.iter().cloned());
+                acc
+            });
+        let suppressions = Vec::from_iter(files.iter().flat_map(|f| f.suppressions()).cloned());
         let analysis = OnceLock::default();
 
         Self {
             locale,
             resource,
+            paths,
+            locations,
+            comments,
+            suppressions,
             analysis,
         }
     }
@@ -45,6 +81,38 @@ impl FluentDocument {
         &self.locale
     }
 
+    /// Returns the paths of the files merged into this document.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.paths.iter().map(PathBuf::as_path)
+    }
+
+    /// Returns the source locations at which the given identifier is defined; one for
+    /// each definition, so duplicates yield several.
+    ///
+    /// Identifiers without a location of their own, such as variants, resolve to the
+    /// location of their top-level message or term.
+    pub fn locations(
+        &self,
+        identifier: &QualifiedIdentifier,
+    ) -> impl Iterator<Item = &SourceLocation> {
+        self.locations
+            .get(identifier)
+            .or_else(|| self.locations.get(&identifier.root()))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Returns the suppression directives written in the files merged into this document.
+    pub fn suppressions(&self) -> impl Iterator<Item = &Suppression> {
+        self.suppressions.iter()
+    }
+
+    /// Runs the `Definitions` analysis now, rather than when first needed, e.g. so that
+    /// several documents may be analysed in parallel.
+    pub(crate) fn analyse(&self) {
+        self.definitions();
+    }
+
     /// Returns a reference to the `Definitions` analysis.
     fn definitions(&self) -> &Definitions {
         self.analysis.get_or_init(|| {
@@ -65,6 +133,18 @@ impl FluentDocument {
         self.definitions().all_identifiers()
     }
 
+    /// Returns an iterator over the identifiers an application can look up directly, i.e.
+    /// messages and their attributes (terms are private to the document).
+    pub fn public_identifiers(&self) -> impl Iterator<Item = QualifiedIdentifier> {
+        self.definitions().public_identifiers()
+    }
+
+    /// Returns an iterator over all messages, terms and attributes referenced from within
+    /// the document's patterns.
+    pub fn references(&self) -> impl Iterator<Item = QualifiedIdentifier> {
+        self.definitions().references()
+    }
+
     /// Returns an iterator over identifiers that appear more than once in the document.
     pub fn duplicate_identifier_names(&self) -> impl Iterator<Item = QualifiedIdentifier> {
         self.definitions().duplicate_identifiers().into_iter()
@@ -76,12 +156,51 @@ impl FluentDocument {
         self.definitions().invalid_references().into_iter()
     }
 
+    /// Returns an iterator over the top-level messages and terms whose patterns reference
+    /// the given identifier.
+    pub fn referrers(
+        &self,
+        identifier: &QualifiedIdentifier,
+    ) -> impl Iterator<Item = QualifiedIdentifier> {
+        self.definitions().referrers(identifier)
+    }
+
     /// Returns the placeholder signature (arguments/variables) for the given identifier,
     /// if it is defined in this document.
     pub fn signature(&self, identifier: &QualifiedIdentifier) -> Option<&Signature> {
         self.definitions().signature(identifier)
     }
 
+    /// Returns the names of the variables, including their `$`, that formatting the given
+    /// identifier uses, if it is defined in this document. A message uses only the variables
+    /// of its value, as each of its attributes is formatted separately with its own.
+    pub fn variables(&self, identifier: &QualifiedIdentifier) -> Option<Vec<&str>> {
+        self.definitions().variables(identifier)
+    }
+
+    /// Returns the `#` comment lines immediately preceding the definition of the given
+    /// top-level identifier, i.e. not separated from it by a blank line, with the leading
+    /// `#` removed.
+    pub fn comments(&self, identifier: &QualifiedIdentifier) -> impl Iterator<Item = &str> {
+        self.comments
+            .get(identifier)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Returns `true` if the top-level identifier's patterns contain any literal text,
+    /// rather than only placeables.
+    pub fn has_text(&self, identifier: &QualifiedIdentifier) -> bool {
+        self.definitions().has_text(identifier)
+    }
+
+    /// Returns the select expressions on numbers, with the identifier of the entry in
+    /// which each is found.
+    pub fn plural_selects(&self) -> impl Iterator<Item = (QualifiedIdentifier, &PluralSelect)> {
+        self.definitions().plural_selects()
+    }
+
     /// Returns an iterator over all AST `Entry` nodes that define the given identifier.
     pub fn entries(&self, identifier: &QualifiedIdentifier) -> impl Iterator<Item = &Entry> {
         self.definitions().entries(identifier)
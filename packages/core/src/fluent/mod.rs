@@ -3,12 +3,15 @@ mod document;
 mod file;
 mod parsed_fluent_file;
 mod path;
+mod plural_select;
 mod qualified_identifier;
 
-pub use definitions::{Definitions, Signature};
+pub use definitions::{Definitions, Signature, SignatureDiff, ValueDiff};
 pub use document::FluentDocument;
 pub use file::FluentFile;
+pub(crate) use parsed_fluent_file::FluentFileContent;
 pub use parsed_fluent_file::ParsedFluentFile;
 #[cfg(test)]
 pub use path::{Path, PathSegment};
+pub use plural_select::{PluralSelect, PluralType};
 pub use qualified_identifier::QualifiedIdentifier;
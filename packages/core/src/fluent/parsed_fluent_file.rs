@@ -1,19 +1,62 @@
-use std::{fs, path::Path};
+use std::{fs, path::Path, str::FromStr, sync::LazyLock};
 
 use fluent4rs::{
     ast::Resource,
     prelude::{Fluent4rsError, Parser},
 };
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::{HasLocale, Locale},
+    audit::Suppression,
+    domain::{HasLocale, Locale, SourceLocation},
     error::LingoraError,
-    fluent::FluentFile,
+    fluent::{FluentFile, QualifiedIdentifier},
 };
 
+/// Matches the start of a message or term definition, which must begin in the first column.
+static ENTRY_START: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<identifier>-?[a-zA-Z][\w-]*)[ ]*=").expect("required valid regex for entry")
+});
+
+/// Matches an attribute definition, which must be indented beneath its entry.
+static ATTRIBUTE_START: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<indent>[ ]+)(?P<attribute>\.[a-zA-Z][\w-]*)[ ]*=")
+        .expect("required valid regex for attribute")
+});
+
+/// Matches an entry suppression directive, e.g. `# lingora-ignore: signature-mismatch`.
+static ENTRY_DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^#[ ]*lingora-ignore(?:[ ]*:(?P<kinds>.*))?[ ]*$")
+        .expect("required valid regex for entry directive")
+});
+
+/// Matches a file suppression directive, e.g. `### lingora-ignore-file`.
+static FILE_DIRECTIVE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^###[ ]*lingora-ignore-file(?:[ ]*:(?P<kinds>.*))?[ ]*$")
+        .expect("required valid regex for file directive")
+});
+
+/// What is found by parsing a Fluent file's content, as held by a `ParseCache` so that
+/// an unchanged file need not be parsed again.
+///
+/// Only files that parse have content, as the parser's error cannot be serialized. The
+/// identifiers are held as written, e.g. `greeting.title`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct FluentFileContent {
+    resource: Resource,
+    locations: Vec<(String, SourceLocation)>,
+    comments: Vec<(String, Vec<String>)>,
+    suppressions: Vec<Suppression>,
+}
+
 pub struct ParsedFluentFile {
     file: FluentFile,
     resource: Result<Resource, Fluent4rsError>,
+    locations: Vec<(QualifiedIdentifier, SourceLocation)>,
+    comments: Vec<(QualifiedIdentifier, Vec<String>)>,
+    suppressions: Vec<Suppression>,
+    error_location: Option<SourceLocation>,
 }
 
 impl ParsedFluentFile {
@@ -40,16 +83,122 @@ impl ParsedFluentFile {
             .err()
             .unwrap_or_default()
     }
+
+    pub fn error_location(&self) -> Option<&SourceLocation> {
+        self.error_location.as_ref()
+    }
+
+    pub fn locations(&self) -> impl Iterator<Item = &(QualifiedIdentifier, SourceLocation)> {
+        self.locations.iter()
+    }
+
+    /// The `#` comment lines directly above each entry, i.e. not separated from it by a
+    /// blank line, with the leading `#` removed.
+    pub fn comments(&self) -> impl Iterator<Item = &(QualifiedIdentifier, Vec<String>)> {
+        self.comments.iter()
+    }
+
+    pub fn suppressions(&self) -> impl Iterator<Item = &Suppression> {
+        self.suppressions.iter()
+    }
+
+    /// Parses the given content of the file.
+    pub(crate) fn parse_source(file: &FluentFile, content: &str) -> Self {
+        let file = file.clone();
+        let resource = Parser::parse(content);
+        let SourceScan {
+            locations,
+            comments,
+            suppressions,
+        } = SourceScan::new(file.path(), content);
+        let error_location = resource
+            .is_err()
+            .then(|| Self::locate_error(file.path(), content));
+        Self {
+            file,
+            resource,
+            locations,
+            comments,
+            suppressions,
+            error_location,
+        }
+    }
+
+    /// Returns what was found in the file's content, or `None` if it does not parse.
+    pub(crate) fn content(&self) -> Option<FluentFileContent> {
+        let resource = self.resource.as_ref().ok()?.clone();
+        let locations =
+            Vec::from_iter(self.locations.iter().filter_map(|(identifier, location)| {
+                identifier
+                    .to_literal_string()
+                    .map(|identifier| (identifier, location.clone()))
+            }));
+        let comments = Vec::from_iter(self.comments.iter().filter_map(|(identifier, comments)| {
+            identifier
+                .to_literal_string()
+                .map(|identifier| (identifier, comments.clone()))
+        }));
+        let suppressions = self.suppressions.clone();
+
+        Some(FluentFileContent {
+            resource,
+            locations,
+            comments,
+            suppressions,
+        })
+    }
+
+    /// Restores the file from what was found in its content when it was last parsed.
+    pub(crate) fn from_content(file: &FluentFile, content: FluentFileContent) -> Self {
+        let FluentFileContent {
+            resource,
+            locations,
+            comments,
+            suppressions,
+        } = content;
+
+        let locations =
+            Vec::from_iter(locations.into_iter().filter_map(|(identifier, location)| {
+                QualifiedIdentifier::from_str(&identifier)
+                    .ok()
+                    .map(|identifier| (identifier, location))
+            }));
+        let comments = Vec::from_iter(comments.into_iter().filter_map(|(identifier, comments)| {
+            QualifiedIdentifier::from_str(&identifier)
+                .ok()
+                .map(|identifier| (identifier, comments))
+        }));
+
+        Self {
+            file: file.clone(),
+            resource: Ok(resource),
+            locations,
+            comments,
+            suppressions,
+            error_location: None,
+        }
+    }
+
+    fn locate_error(path: &Path, content: &str) -> SourceLocation {
+        Parser::parse_with_junk(content)
+            .ok()
+            .and_then(|resource| {
+                resource
+                    .junk()
+                    .first()
+                    .and_then(|junk| content.find(junk.to_string().trim()))
+            })
+            .map(|offset| SourceLocation::from_offset(path, content, offset))
+            .unwrap_or_else(|| SourceLocation::start_of(path))
+    }
 }
 
 impl TryFrom<&FluentFile> for ParsedFluentFile {
     type Error = LingoraError;
 
     fn try_from(file: &FluentFile) -> Result<Self, Self::Error> {
-        let file = file.clone();
         let content = fs::read_to_string(file.path())?;
-        let resource = Parser::parse(content.as_str());
-        Ok(Self { file, resource })
+        Ok(Self::parse_source(file, &content))
     }
 }
 
@@ -58,3 +207,116 @@ impl HasLocale for ParsedFluentFile {
         self.file.locale()
     }
 }
+
+type Directive = (SourceLocation, Vec<String>);
+
+/// The entry definitions, entry comments and suppression directives found by scanning a
+/// file's lines.
+///
+/// The scan is textual, as the parsed resource has no source positions, and does not
+/// retain the blank lines that determine whether a comment belongs to an entry.
+#[derive(Default)]
+struct SourceScan {
+    locations: Vec<(QualifiedIdentifier, SourceLocation)>,
+    comments: Vec<(QualifiedIdentifier, Vec<String>)>,
+    suppressions: Vec<Suppression>,
+}
+
+impl SourceScan {
+    fn new(path: &Path, content: &str) -> Self {
+        let mut scan = Self::default();
+        let mut current: Option<(String, usize)> = None;
+        let mut pending: Vec<Directive> = Vec::new();
+        let mut attached: Vec<Directive> = Vec::new();
+        let mut comments: Vec<String> = Vec::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+
+            if let Some(captures) = ENTRY_START.captures(line) {
+                scan.close_entry(current.take(), &mut attached, line_number);
+
+                let identifier = &captures["identifier"];
+                let comments = std::mem::take(&mut comments);
+                if let Ok(qualified) = QualifiedIdentifier::from_str(identifier) {
+                    let location = SourceLocation::new(path, line_number, 1);
+                    if !comments.is_empty() {
+                        scan.comments.push((qualified.clone(), comments));
+                    }
+                    scan.locations.push((qualified, location));
+                    current = Some((identifier.to_string(), line_number));
+                    attached = std::mem::take(&mut pending);
+                }
+            } else if let Some(captures) = ATTRIBUTE_START.captures(line) {
+                if let Some((root, _)) = &current
+                    && let Ok(identifier) =
+                        QualifiedIdentifier::from_str(&format!("{root}{}", &captures["attribute"]))
+                {
+                    let column = captures["indent"].len() + 1;
+                    let location = SourceLocation::new(path, line_number, column);
+                    scan.locations.push((identifier, location));
+                }
+            } else if line.starts_with(' ') || line.trim().is_empty() {
+                // Continuation of the current entry, or a blank line which detaches any
+                // preceding comments from the next entry...
+                if line.trim().is_empty() {
+                    scan.detach(&mut pending);
+                    comments.clear();
+                }
+            } else {
+                scan.close_entry(current.take(), &mut attached, line_number);
+
+                let location = SourceLocation::new(path, line_number, 1);
+                if let Some(captures) = FILE_DIRECTIVE.captures(line) {
+                    let kinds =
+                        Suppression::parse_kinds(captures.name("kinds").map(|m| m.as_str()));
+                    scan.suppressions.push(Suppression::file(location, kinds));
+                } else if let Some(captures) = ENTRY_DIRECTIVE.captures(line) {
+                    let kinds =
+                        Suppression::parse_kinds(captures.name("kinds").map(|m| m.as_str()));
+                    pending.push((location, kinds));
+                } else if !(line.starts_with("# ") || line == "#") {
+                    scan.detach(&mut pending);
+                }
+
+                // Only `#` comments belong to an entry; group and resource comments do not...
+                match line.strip_prefix('#') {
+                    Some(comment) if !comment.starts_with('#') => {
+                        comments.push(comment.trim().to_string());
+                    }
+                    _ => comments.clear(),
+                }
+            }
+        }
+
+        let end = content.lines().count() + 1;
+        scan.close_entry(current, &mut attached, end);
+        scan.detach(&mut pending);
+
+        scan
+    }
+
+    /// Directives not directly followed by an entry apply to nothing, and so are kept
+    /// only to be reported as unused.
+    fn detach(&mut self, pending: &mut Vec<Directive>) {
+        self.suppressions.extend(
+            pending
+                .drain(..)
+                .map(|(location, kinds)| Suppression::lines(location, kinds, 0..0)),
+        );
+    }
+
+    fn close_entry(
+        &mut self,
+        entry: Option<(String, usize)>,
+        attached: &mut Vec<Directive>,
+        end: usize,
+    ) {
+        let start = entry.map(|(_, start)| start).unwrap_or(end);
+        self.suppressions.extend(
+            attached
+                .drain(..)
+                .map(|(location, kinds)| Suppression::lines(location, kinds, start..end)),
+        );
+    }
+}
@@ -25,6 +25,21 @@ impl Path {
 
         Self(path)
     }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn root(&self) -> Self {
+        Self(self.0.iter().take(1).cloned().collect())
+    }
+
+    pub fn is_public(&self) -> bool {
+        matches!(
+            self.0.as_slice(),
+            [PathSegment::Message(_)] | [PathSegment::Message(_), PathSegment::Attribute(_)]
+        )
+    }
 }
 
 impl std::cmp::PartialOrd for Path {
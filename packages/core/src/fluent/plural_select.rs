@@ -0,0 +1,212 @@
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    sync::{LazyLock, Mutex, PoisonError},
+};
+
+use icu_plurals::{PluralCategory, PluralRules};
+
+use crate::domain::Locale;
+
+/// The CLDR plural categories, as written in variant keys, e.g. `[few]`.
+const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// The everyday counts, e.g. of unread emails, for whose categories a select is expected
+/// to have a variant. Categories only of larger, or fractional, numbers, e.g. `many` for
+/// a million in French, are left to the default variant.
+const COUNTS: RangeInclusive<u32> = 0..=1000;
+
+/// The categories of each locale's rules, as the rules are built from the bundled data.
+static CATEGORIES_BY_LOCALE: LazyLock<Mutex<HashMap<(Locale, PluralType), Categories>>> =
+    LazyLock::new(Mutex::default);
+
+/// The plural categories of a locale's rules; all those the rules distinguish, and those
+/// of the everyday `COUNTS`.
+#[derive(Clone, Debug)]
+struct Categories {
+    all: Vec<&'static str>,
+    counted: Vec<&'static str>,
+}
+
+/// Whether a numeric selector chooses its variant by cardinal (`1 day`) or
+/// ordinal (`1st day`) plural rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralType {
+    Cardinal,
+    Ordinal,
+}
+
+impl PluralType {
+    /// Returns the plural categories distinguished by the locale's rules, using the
+    /// bundled CLDR data.
+    pub fn categories(&self, locale: &Locale) -> Vec<&'static str> {
+        self.cached(locale).all
+    }
+
+    /// Returns the plural categories of the locale's rules for the everyday `COUNTS`.
+    pub fn counted_categories(&self, locale: &Locale) -> Vec<&'static str> {
+        self.cached(locale).counted
+    }
+
+    fn cached(&self, locale: &Locale) -> Categories {
+        let mut cache = CATEGORIES_BY_LOCALE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        cache
+            .entry((locale.clone(), *self))
+            .or_insert_with(|| self.load(locale))
+            .clone()
+    }
+
+    fn load(&self, locale: &Locale) -> Categories {
+        let prefs = locale.language_identifier().into();
+        let rules = match self {
+            PluralType::Cardinal => PluralRules::try_new_cardinal(prefs),
+            PluralType::Ordinal => PluralRules::try_new_ordinal(prefs),
+        };
+
+        match rules {
+            Ok(rules) => {
+                let counted = Vec::from_iter(COUNTS.map(|n| Self::name(rules.category_for(n))));
+                let all = Vec::from_iter(rules.categories().map(Self::name));
+                Categories {
+                    counted: Vec::from_iter(
+                        all.iter()
+                            .copied()
+                            .filter(|category| counted.contains(category)),
+                    ),
+                    all,
+                }
+            }
+            Err(_) => Categories {
+                all: Vec::from(CATEGORIES),
+                counted: Vec::from(CATEGORIES),
+            },
+        }
+    }
+
+    fn name(category: PluralCategory) -> &'static str {
+        match category {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// A select expression on a numeric selector, i.e. `NUMBER()` or a variable selecting
+/// between plural categories, whose variant keys should be the categories of the locale.
+///
+/// Numeric keys, e.g. `[0]`, select exact values and so are not plural categories.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluralSelect {
+    plural_type: PluralType,
+    keys: Vec<String>,
+}
+
+impl PluralSelect {
+    /// Returns whether a variant key is a CLDR plural category, e.g. `few`.
+    pub fn is_category(key: &str) -> bool {
+        CATEGORIES.contains(&key)
+    }
+
+    /// A select expression on `NUMBER()`, which is always numeric.
+    pub fn number(plural_type: PluralType, keys: Vec<String>) -> Self {
+        Self { plural_type, keys }
+    }
+
+    /// A select expression on a variable, which is taken to be numeric only if a key,
+    /// other than the catch-all `[other]`, is a plural category.
+    pub fn variable(keys: Vec<String>) -> Option<Self> {
+        keys.iter()
+            .any(|key| key != "other" && CATEGORIES.contains(&key.as_str()))
+            .then_some(Self::number(PluralType::Cardinal, keys))
+    }
+
+    /// Returns the keys that the locale's plural rules can never select.
+    pub fn impossible_categories(&self, locale: &Locale) -> Vec<&str> {
+        let categories = self.plural_type.categories(locale);
+        self.keys
+            .iter()
+            .map(String::as_str)
+            .filter(|key| !categories.contains(key))
+            .collect()
+    }
+
+    /// Returns the locale's plural categories of the everyday counts that have no key.
+    pub fn missing_categories(&self, locale: &Locale) -> Vec<&'static str> {
+        self.plural_type
+            .counted_categories(locale)
+            .into_iter()
+            .filter(|category| !self.keys.iter().any(|key| key == category))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_support::locale;
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        Vec::from_iter(keys.iter().map(|key| key.to_string()))
+    }
+
+    #[test]
+    fn categories_are_per_locale() {
+        assert_eq!(
+            PluralType::Cardinal.categories(&locale("en-GB")),
+            ["one", "other"]
+        );
+        assert_eq!(
+            PluralType::Cardinal.categories(&locale("pl-PL")),
+            ["one", "few", "many", "other"]
+        );
+        assert_eq!(
+            PluralType::Ordinal.categories(&locale("en-GB")),
+            ["one", "two", "few", "other"]
+        );
+        assert_eq!(PluralType::Cardinal.categories(&locale("ja-JP")), ["other"]);
+    }
+
+    #[test]
+    fn counted_categories_omit_those_only_of_large_numbers() {
+        assert_eq!(
+            PluralType::Cardinal.categories(&locale("fr-FR")),
+            ["one", "many", "other"]
+        );
+        assert_eq!(
+            PluralType::Cardinal.counted_categories(&locale("fr-FR")),
+            ["one", "other"]
+        );
+        assert_eq!(
+            PluralType::Cardinal.counted_categories(&locale("pl-PL")),
+            ["one", "few", "many"]
+        );
+    }
+
+    #[test]
+    fn variables_are_numeric_when_keyed_by_plural_categories() {
+        assert!(PluralSelect::variable(keys(&["one", "other"])).is_some());
+        assert!(PluralSelect::variable(keys(&["male", "female", "other"])).is_none());
+    }
+
+    #[test]
+    fn impossible_and_missing_categories_are_found() {
+        let select = PluralSelect::number(PluralType::Cardinal, keys(&["one", "few", "other"]));
+
+        assert_eq!(select.impossible_categories(&locale("en-GB")), ["few"]);
+        assert!(select.missing_categories(&locale("en-GB")).is_empty());
+        assert!(select.impossible_categories(&locale("pl-PL")).is_empty());
+        assert_eq!(select.missing_categories(&locale("pl-PL")), ["many"]);
+
+        let select = PluralSelect::number(PluralType::Cardinal, keys(&["one", "other"]));
+        for locale in ["it-IT", "fr-FR", "es-ES", "pt-PT"].map(locale) {
+            assert!(select.missing_categories(&locale).is_empty(), "{locale}");
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use regex::Regex;
+use serde::{Serialize, Serializer};
 
 use crate::{
     error::LingoraError,
@@ -34,6 +35,11 @@ impl QualifiedIdentifier {
         Self::from(&self.0.normalized())
     }
 
+    /// Returns the top-level message or term identifier, e.g. `greeting` for `greeting.title`.
+    pub fn root(&self) -> QualifiedIdentifier {
+        Self::from(&self.0.root())
+    }
+
     /// Returns the normalized string representation.
     pub fn to_normalized_string(&self) -> String {
         self.normalized().to_meta_string()
@@ -43,6 +49,26 @@ impl QualifiedIdentifier {
     pub fn to_meta_string(&self) -> String {
         self.0.to_string()
     }
+
+    /// Returns the identifier as it is written in a Rust literal, e.g. `greeting.title`,
+    /// if it is a message, term or attribute.
+    pub fn to_literal_string(&self) -> Option<String> {
+        match self.0.segments() {
+            [PathSegment::Message(name) | PathSegment::Term(name)] => Some(name.clone()),
+            [
+                PathSegment::Message(name) | PathSegment::Term(name),
+                PathSegment::Attribute(attribute),
+            ] => Some(format!("{name}{attribute}")),
+            _ => None,
+        }
+    }
+}
+
+/// Serialized in its meta string form, as used in issue messages.
+impl Serialize for QualifiedIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.to_meta_string())
+    }
 }
 
 impl From<&Path> for QualifiedIdentifier {
@@ -8,10 +8,12 @@ mod config;
 mod domain;
 mod error;
 mod fluent;
+mod parallel;
 mod renderers;
 mod rust;
 #[cfg(test)]
 mod test_support;
+mod watch;
 
 /// Prelude module for convenient imports of the most commonly used types and traits
 /// in the `lingora-core` crate.
@@ -22,13 +24,18 @@ mod test_support;
 pub mod prelude {
     pub use super::{
         audit::{
-            AuditEngine, AuditIssue, AuditResult, AuditedDocument, DocumentRole, Kind, Subject,
-            Workspace,
+            AuditEngine, AuditIssue, AuditResult, AuditedDocument, Baseline, DocumentRole, Kind,
+            ParseCache, Subject, Workspace,
         },
-        config::{CoreArgs, LingoraToml},
-        domain::{LanguageRoot, Locale},
+        config::{CoreArgs, LingoraToml, Severity},
+        domain::{LanguageRoot, Locale, SourceLocation},
         error::LingoraError,
-        fluent::{FluentDocument, QualifiedIdentifier},
-        renderers::{AnalysisRenderer, DioxusI18nConfigRenderer},
+        fluent::{FluentDocument, QualifiedIdentifier, SignatureDiff, ValueDiff},
+        renderers::{
+            AnalysisRenderer, DioxusI18nConfigRenderer, FluentStubRenderer, JsonRenderer,
+            JunitRenderer, SarifRenderer,
+        },
+        rust::IdentifierLiteral,
+        watch::SourceWatcher,
     };
 }
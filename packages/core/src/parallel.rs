@@ -0,0 +1,51 @@
+//! Work over each of a slice's items, spread across a thread pool if the `parallel`
+//! feature is enabled, or otherwise done in turn.
+//!
+//! Results are returned in the order of the items either way, so that what is found does
+//! not depend on the feature, nor on the order in which the work completes.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Maps each of the items, returning the results in the items' order.
+pub(crate) fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    let results = items.par_iter().map(f).collect();
+
+    #[cfg(not(feature = "parallel"))]
+    let results = items.iter().map(f).collect();
+
+    results
+}
+
+/// Calls `f` with each of the items, e.g. to initialise what is computed on demand.
+pub(crate) fn for_each<T, F>(items: &[T], f: F)
+where
+    T: Sync,
+    F: Fn(&T) + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    items.par_iter().for_each(f);
+
+    #[cfg(not(feature = "parallel"))]
+    items.iter().for_each(f);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn results_will_be_in_the_order_of_the_items() {
+        let items = Vec::from_iter(0..1000);
+
+        let results = map(&items, |item| item * 2);
+
+        assert_eq!(results, Vec::from_iter((0..1000).map(|item| item * 2)));
+    }
+}
@@ -16,7 +16,9 @@ use crate::{
 ///   - Any regional/script variants of that language
 /// - Orphaned locales (no matching base root)
 ///
-/// Issues for each locale are sorted by `Kind` for consistent readability.
+/// Issues for each locale are sorted by `Kind` for consistent readability. Each is prefixed
+/// by its severity, and followed by the changes that make up a signature mismatch, if it is
+/// one, and then the `path:line:column` of its source locations.
 pub struct AnalysisRenderer {
     workspace: Workspace,
     issues: BTreeMap<Option<Locale>, Vec<AuditIssue>>,
@@ -71,9 +73,11 @@ impl AnalysisRenderer {
             issues.iter().try_for_each(|(subject, issues)| {
                 writeln!(out, "Workspace: {subject}")?;
 
-                issues
-                    .iter()
-                    .try_for_each(|issue| writeln!(out, "{:10} {}", "", issue.message()))
+                issues.iter().try_for_each(|issue| {
+                    writeln!(out, "{:10} {}: {}", "", issue.severity(), issue.message())?;
+                    Self::render_help(out, issue)?;
+                    Self::render_locations(out, issue)
+                })
             })?;
         }
 
@@ -118,13 +122,46 @@ impl AnalysisRenderer {
                 std::cmp::Ordering::Greater => std::cmp::Ordering::Greater,
             });
 
-            issues
-                .iter()
-                .try_for_each(|issue| writeln!(out, "{:11}{issue}", ""))?;
+            issues.iter().try_for_each(|issue| {
+                writeln!(out, "{:11}{}: {issue}", "", issue.severity())?;
+                Self::render_signature_diff(out, issue)?;
+                Self::render_help(out, issue)?;
+                Self::render_locations(out, issue)
+            })?;
         } else {
             writeln!(out, "{:10} {} - Ok", title, locale)?;
         }
 
         Ok(())
     }
+
+    fn render_signature_diff<W: io::Write>(
+        out: &mut W,
+        issue: &AuditIssue,
+    ) -> Result<(), LingoraError> {
+        issue
+            .signature_diff()
+            .into_iter()
+            .flat_map(|diff| diff.changes())
+            .try_for_each(|change| writeln!(out, "{:13}- {change}", ""))?;
+
+        Ok(())
+    }
+
+    fn render_help<W: io::Write>(out: &mut W, issue: &AuditIssue) -> Result<(), LingoraError> {
+        if let Some(help) = issue.help() {
+            writeln!(out, "{:13}help: {help}", "")?;
+        }
+
+        Ok(())
+    }
+
+    fn render_locations<W: io::Write>(out: &mut W, issue: &AuditIssue) -> Result<(), LingoraError> {
+        issue
+            .locations()
+            .iter()
+            .try_for_each(|location| writeln!(out, "{:13}--> {location}", ""))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,242 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    io,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{
+    audit::{AuditResult, Kind},
+    domain::SourceLocation,
+    error::LingoraError,
+    fluent::QualifiedIdentifier,
+};
+
+/// Renderer that generates stub Fluent entries for the identifier literals used in the
+/// Rust sources, but not defined in the canonical document, i.e. those reported as
+/// `undefined_identifier_literal` issues.
+///
+/// Each stub is a message whose value, and that of each attribute used, is the literal
+/// itself, with a placeable for each named argument passed to the macro, e.g.
+/// `emails = emails { $count }` for `t!("emails", count: n)`. Stubs are grouped by the
+/// Rust file in which they are first used, under a group comment, and each is preceded
+/// by a comment listing the locations of its uses.
+///
+/// Attributes used on messages already in the canonical document are not stubbed, as
+/// they cannot be added by appending to the document.
+pub struct FluentStubRenderer<'a> {
+    audit_result: &'a AuditResult,
+}
+
+/// The uses of a single undefined message, and its attributes.
+#[derive(Default)]
+struct Stub {
+    locations: Vec<SourceLocation>,
+    value: Option<BTreeSet<String>>,
+    attributes: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl<'a> FluentStubRenderer<'a> {
+    /// Creates a new renderer for the Rust sources and canonical document of the audit.
+    pub fn new(audit_result: &'a AuditResult) -> Self {
+        Self { audit_result }
+    }
+
+    /// Renders the stubs, if any, to the given writer; the output starts with a blank line
+    /// so that it can be appended to an existing Fluent file.
+    pub fn render<W: io::Write>(&self, out: &mut W) -> Result<(), LingoraError> {
+        let stubs = self.stubs();
+
+        let mut groups: Vec<(PathBuf, Vec<(&String, &Stub)>)> = Vec::new();
+        stubs.iter().for_each(|(message, stub)| {
+            let path = stub.locations[0].path();
+            match groups.iter_mut().find(|(p, _)| p == path) {
+                Some((_, group)) => group.push((message, stub)),
+                None => groups.push((path.to_path_buf(), vec![(message, stub)])),
+            }
+        });
+
+        for (path, group) in groups {
+            writeln!(out)?;
+            writeln!(out, "## {}", path.display())?;
+
+            for (message, stub) in group {
+                writeln!(out)?;
+                for location in &stub.locations {
+                    writeln!(out, "# {location}")?;
+                }
+
+                match &stub.value {
+                    Some(variables) => {
+                        writeln!(out, "{message} = {}", placeholder(message, variables))?
+                    }
+                    None => writeln!(out, "{message} =")?,
+                }
+
+                for (attribute, variables) in &stub.attributes {
+                    let literal = format!("{message}.{attribute}");
+                    writeln!(
+                        out,
+                        "    .{attribute} = {}",
+                        placeholder(&literal, variables)
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collects the stubs, in the order of their first use.
+    fn stubs(&self) -> Vec<(String, Stub)> {
+        let undefined = HashSet::<&SourceLocation>::from_iter(
+            self.audit_result
+                .issues()
+                .filter(|issue| *issue.kind() == Kind::UndefinedIdentifierLiteral)
+                .flat_map(|issue| issue.locations()),
+        );
+
+        let defined = self
+            .audit_result
+            .document(self.audit_result.canonical_locale())
+            .map(|document| HashSet::<QualifiedIdentifier>::from_iter(document.identifiers()))
+            .unwrap_or_default();
+
+        let mut stubs: Vec<(String, Stub)> = Vec::new();
+
+        for (location, call) in self.audit_result.macro_calls() {
+            if !undefined.contains(location) {
+                continue;
+            }
+
+            let Some(identifier) = call
+                .literal()
+                .and_then(|literal| QualifiedIdentifier::from_str(literal).ok())
+            else {
+                continue;
+            };
+
+            if defined.contains(&identifier.root()) {
+                continue;
+            }
+
+            let Some(literal) = identifier
+                .path()
+                .is_public()
+                .then(|| identifier.to_literal_string())
+                .flatten()
+            else {
+                continue;
+            };
+
+            let (message, attribute) = match literal.split_once('.') {
+                Some((message, attribute)) => (message.to_string(), Some(attribute)),
+                None => (literal.clone(), None),
+            };
+
+            let index = match stubs.iter().position(|(m, _)| *m == message) {
+                Some(index) => index,
+                None => {
+                    stubs.push((message, Stub::default()));
+                    stubs.len() - 1
+                }
+            };
+            let stub = &mut stubs[index].1;
+
+            let variables = match attribute {
+                Some(attribute) => stub.attributes.entry(attribute.to_string()).or_default(),
+                None => stub.value.get_or_insert_default(),
+            };
+            variables.extend(call.arguments().into_iter().flatten().map(String::from));
+
+            if !stub.locations.contains(location) {
+                stub.locations.push(location.clone());
+            }
+        }
+
+        stubs
+    }
+}
+
+/// The placeholder value of a stub, i.e. its literal followed by its variables.
+fn placeholder(literal: &str, variables: &BTreeSet<String>) -> String {
+    variables.iter().fold(literal.to_string(), |acc, variable| {
+        format!("{acc} {{ ${variable} }}")
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        audit::AuditEngine,
+        config::LingoraToml,
+        test_support::{with_temp_fluent_files, with_temp_rust_files},
+    };
+
+    #[test]
+    fn will_render_stubs_for_undefined_identifier_literals() {
+        with_temp_fluent_files(&[("en-GB", "greeting = Hello\n")], |fluent_files| {
+            with_temp_rust_files(
+                &[(
+                    "app",
+                    r#"
+fn app() {
+    let greeting = t!("greeting");
+    let title = t!("greeting.title");
+    let emails = t!("emails", count: n);
+    let login = t!("login.title");
+    let again = t!("emails", count: n, name: name);
+    let tooltip = t!("login.tooltip", user: user);
+    let dynamic = t!(&format!("status-{}", s));
+}
+"#,
+                )],
+                |rust_files| {
+                    let fluent_dir = fluent_files[0].path().parent().expect("temp dir");
+                    let rust_dir = rust_files[0].path().parent().expect("temp dir");
+                    let toml = format!(
+                        r#"
+[lingora]
+fluent_sources = [{:?}]
+canonical = "en-GB"
+
+[dioxus_i18n]
+rust_sources = [{:?}]
+"#,
+                        fluent_dir.display().to_string(),
+                        rust_dir.display().to_string()
+                    );
+                    let settings = LingoraToml::from_str(&toml).expect("valid settings");
+                    let audit_result = AuditEngine::try_from(&settings)
+                        .and_then(|engine| engine.run())
+                        .expect("valid audit");
+
+                    let mut out = Vec::new();
+                    FluentStubRenderer::new(&audit_result)
+                        .render(&mut out)
+                        .expect("valid render");
+
+                    let path = rust_files[0].path().display();
+                    let expected = format!(
+                        r#"
+## {path}
+
+# {path}:4:21
+# {path}:6:20
+emails = emails {{ $count }} {{ $name }}
+
+# {path}:5:20
+# {path}:7:22
+login =
+    .title = login.title
+    .tooltip = login.tooltip {{ $user }}
+"#
+                    );
+
+                    assert_eq!(String::from_utf8_lossy(&out), expected);
+                },
+            );
+        });
+    }
+}
@@ -0,0 +1,26 @@
+use std::io;
+
+use crate::{audit::AuditResult, error::LingoraError};
+
+/// A machine-readable JSON renderer for `AuditResult`.
+///
+/// The output is the serialized `AuditResult`, whose versioned schema is described in
+/// `docs/json_output.md`. It is intended for dashboards and other tooling, rather than
+/// for reading directly.
+pub struct JsonRenderer<'a> {
+    audit_result: &'a AuditResult,
+}
+
+impl<'a> JsonRenderer<'a> {
+    /// Creates a new renderer for the given `AuditResult`.
+    pub fn new(audit_result: &'a AuditResult) -> Self {
+        Self { audit_result }
+    }
+
+    /// Renders the audit result, as pretty-printed JSON, to the given writer.
+    pub fn render<W: io::Write>(&self, out: &mut W) -> Result<(), LingoraError> {
+        serde_json::to_writer_pretty(&mut *out, self.audit_result)?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+};
+
+use crate::{
+    audit::{AuditIssue, AuditResult, DocumentRole, Kind},
+    config::Severity,
+    error::LingoraError,
+};
+
+/// A [JUnit XML](https://github.com/testmoapp/junitxml) renderer for `AuditResult`, so
+/// that translation health can be shown alongside unit test results in CI dashboards.
+///
+/// Produces:
+/// - a `workspace` test suite for issues not related to a locale (e.g. Rust sources)
+/// - a test suite per locale, ordered canonical, primaries, variants then orphans
+/// - within each suite, a test case per `Kind`, which fails with the messages of that
+///   kind's error issues; warnings are reported as the test case's output
+pub struct JunitRenderer<'a> {
+    audit_result: &'a AuditResult,
+}
+
+struct Suite<'a> {
+    name: String,
+    role: Option<DocumentRole>,
+    issues: Vec<&'a AuditIssue>,
+}
+
+impl<'a> JunitRenderer<'a> {
+    /// Creates a new renderer for the given `AuditResult`.
+    pub fn new(audit_result: &'a AuditResult) -> Self {
+        Self { audit_result }
+    }
+
+    /// Renders the JUnit XML report to the given writer.
+    pub fn render<W: io::Write>(&self, out: &mut W) -> Result<(), LingoraError> {
+        let suites = self.suites();
+
+        let tests = suites.len() * Kind::ALL.len();
+        let failures = suites.iter().map(Self::failures).sum::<usize>();
+
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            out,
+            r#"<testsuites name="lingora" tests="{tests}" failures="{failures}" errors="0">"#
+        )?;
+        suites
+            .iter()
+            .try_for_each(|suite| Self::render_suite(out, suite))?;
+        writeln!(out, "</testsuites>")?;
+
+        Ok(())
+    }
+
+    fn suites(&self) -> Vec<Suite<'a>> {
+        let mut issues_by_locale = self.audit_result.issues().fold(
+            BTreeMap::new(),
+            |mut acc: BTreeMap<_, Vec<_>>, issue| {
+                acc.entry(issue.locale()).or_default().push(issue);
+                acc
+            },
+        );
+
+        let mut locales = Vec::from_iter(
+            self.audit_result
+                .document_locales()
+                .chain(issues_by_locale.keys().flatten())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .map(|locale| {
+                    let role = self.audit_result.document(locale).map(|d| d.role());
+                    (locale.clone(), role)
+                }),
+        );
+        locales.sort_by_key(|(_, role)| Self::rank(*role));
+
+        let workspace = Suite {
+            name: String::from("workspace"),
+            role: None,
+            issues: issues_by_locale.remove(&None).unwrap_or_default(),
+        };
+
+        std::iter::once(workspace)
+            .chain(locales.into_iter().map(|(locale, role)| Suite {
+                name: locale.to_string(),
+                role,
+                issues: issues_by_locale.remove(&Some(locale)).unwrap_or_default(),
+            }))
+            .collect()
+    }
+
+    fn rank(role: Option<DocumentRole>) -> usize {
+        match role {
+            Some(DocumentRole::Canonical) => 0,
+            Some(DocumentRole::Primary) => 1,
+            Some(DocumentRole::Variant) => 2,
+            Some(DocumentRole::Orphan) => 3,
+            None => 4,
+        }
+    }
+
+    fn failures(suite: &Suite) -> usize {
+        Kind::ALL
+            .iter()
+            .filter(|kind| {
+                suite
+                    .issues
+                    .iter()
+                    .any(|i| i.kind() == *kind && i.severity() == Severity::Error)
+            })
+            .count()
+    }
+
+    fn render_suite<W: io::Write>(out: &mut W, suite: &Suite) -> Result<(), LingoraError> {
+        let name = escape(&suite.name);
+        let tests = Kind::ALL.len();
+        let failures = Self::failures(suite);
+
+        writeln!(
+            out,
+            r#"  <testsuite name="{name}" tests="{tests}" failures="{failures}" errors="0">"#
+        )?;
+
+        if let Some(role) = suite.role {
+            writeln!(out, "    <properties>")?;
+            writeln!(out, r#"      <property name="role" value="{role}"/>"#)?;
+            writeln!(out, "    </properties>")?;
+        }
+
+        Kind::ALL.iter().try_for_each(|kind| {
+            let mut issues =
+                Vec::from_iter(suite.issues.iter().filter(|i| i.kind() == kind).copied());
+            issues.sort_by(|a, b| (a.subject(), a.message()).cmp(&(b.subject(), b.message())));
+            Self::render_case(out, &name, kind, &issues)
+        })?;
+
+        writeln!(out, "  </testsuite>")?;
+
+        Ok(())
+    }
+
+    fn render_case<W: io::Write>(
+        out: &mut W,
+        suite_name: &str,
+        kind: &Kind,
+        issues: &[&AuditIssue],
+    ) -> Result<(), LingoraError> {
+        let id = kind.id();
+        let classname = format!("lingora.{suite_name}");
+
+        let (errors, warnings): (Vec<_>, Vec<_>) = issues
+            .iter()
+            .copied()
+            .partition(|issue| issue.severity() == Severity::Error);
+
+        if issues.is_empty() {
+            writeln!(
+                out,
+                r#"    <testcase name="{id}" classname="{classname}"/>"#
+            )?;
+        } else {
+            writeln!(out, r#"    <testcase name="{id}" classname="{classname}">"#)?;
+
+            if !errors.is_empty() {
+                let message = escape(&format!("{} {id} issue(s)", errors.len()));
+                writeln!(out, r#"      <failure message="{message}" type="{id}">"#)?;
+                Self::render_issues(out, &errors)?;
+                writeln!(out, "      </failure>")?;
+            }
+
+            if !warnings.is_empty() {
+                writeln!(out, "      <system-out>")?;
+                Self::render_issues(out, &warnings)?;
+                writeln!(out, "      </system-out>")?;
+            }
+
+            writeln!(out, "    </testcase>")?;
+        }
+
+        Ok(())
+    }
+
+    fn render_issues<W: io::Write>(
+        out: &mut W,
+        issues: &[&AuditIssue],
+    ) -> Result<(), LingoraError> {
+        issues.iter().try_for_each(|issue| {
+            let line = format!("{}: {}: {}", issue.severity(), issue.subject(), issue);
+            writeln!(out, "{}", escape(&line))?;
+            if let Some(help) = issue.help() {
+                writeln!(out, "  help: {}", escape(&help))?;
+            }
+            issue
+                .locations()
+                .iter()
+                .try_for_each(|location| writeln!(out, "  --> {}", escape(&location.to_string())))
+        })?;
+
+        Ok(())
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.chars()
+        .fold(String::with_capacity(s.len()), |mut acc, c| {
+            match c {
+                '&' => acc.push_str("&amp;"),
+                '<' => acc.push_str("&lt;"),
+                '>' => acc.push_str("&gt;"),
+                '"' => acc.push_str("&quot;"),
+                '\'' => acc.push_str("&apos;"),
+                c => acc.push(c),
+            }
+            acc
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn will_escape_xml_special_characters() {
+        assert_eq!(
+            escape(r#"<a href="x">'b' & c</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&apos;b&apos; &amp; c&lt;/a&gt;"
+        );
+    }
+}
@@ -1,5 +1,13 @@
 mod analysis_renderer;
 mod dioxus_i18n_config_renderer;
+mod fluent_stub_renderer;
+mod json_renderer;
+mod junit_renderer;
+mod sarif_renderer;
 
 pub use analysis_renderer::AnalysisRenderer;
 pub use dioxus_i18n_config_renderer::DioxusI18nConfigRenderer;
+pub use fluent_stub_renderer::FluentStubRenderer;
+pub use json_renderer::JsonRenderer;
+pub use junit_renderer::JunitRenderer;
+pub use sarif_renderer::SarifRenderer;
@@ -0,0 +1,347 @@
+use std::{
+    fmt::Write as _,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{Value, json};
+
+use crate::{
+    audit::{AuditIssue, AuditResult, Kind, Subject},
+    config::Severity,
+    domain::{Locale, SourceLocation},
+    error::LingoraError,
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The base id for relative artifact paths, i.e. the directory lingora was run from.
+const SOURCE_ROOT: &str = "%SRCROOT%";
+
+/// The configuration file results are located at when neither they nor their subject
+/// have a file, unless the renderer is given the one actually read.
+const DEFAULT_CONFIG_FILE: &str = "Lingora.toml";
+
+/// A [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// renderer for `AuditResult`, suitable for uploading as code-scanning alerts.
+///
+/// Produces a single run in which:
+/// - each `Kind` is a rule, identified by `Kind::id`
+/// - each `AuditIssue` is a result of its kind's rule, at the level of its severity,
+///   located at its first source location, with any further locations as related locations;
+///   an issue without source locations is located at the start of its subject's file (the
+///   first document of its locale) or, failing that, of the configuration file
+pub struct SarifRenderer<'a> {
+    audit_result: &'a AuditResult,
+    config_file: PathBuf,
+}
+
+impl<'a> SarifRenderer<'a> {
+    /// Creates a new renderer for the given `AuditResult`.
+    pub fn new(audit_result: &'a AuditResult) -> Self {
+        Self {
+            audit_result,
+            config_file: PathBuf::from(DEFAULT_CONFIG_FILE),
+        }
+    }
+
+    /// Locates results that have no file of their own at the given configuration file,
+    /// rather than at `Lingora.toml`.
+    pub fn with_config_file(mut self, config_file: &Path) -> Self {
+        self.config_file = config_file.to_path_buf();
+        self
+    }
+
+    /// Renders the SARIF log, as pretty-printed JSON, to the given writer.
+    pub fn render<W: io::Write>(&self, out: &mut W) -> Result<(), LingoraError> {
+        let mut issues = Vec::from_iter(self.audit_result.issues());
+        issues.sort_by(|a, b| {
+            (a.kind(), a.subject(), a.message()).cmp(&(b.kind(), b.subject(), b.message()))
+        });
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "lingora",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_HOMEPAGE"),
+                        "rules": Vec::from_iter(Kind::ALL.iter().map(Self::rule)),
+                    }
+                },
+                "results": Vec::from_iter(issues.into_iter().map(|issue| self.result(issue))),
+            }]
+        });
+
+        serde_json::to_writer_pretty(&mut *out, &log)?;
+        writeln!(out)?;
+        Ok(())
+    }
+
+    fn rule(kind: &Kind) -> Value {
+        json!({
+            "id": kind.id(),
+            "shortDescription": { "text": kind.description() },
+            "defaultConfiguration": { "level": Self::level(kind.default_severity()) },
+        })
+    }
+
+    fn result(&self, issue: &AuditIssue) -> Value {
+        let kind = issue.kind();
+        let rule_index = Kind::ALL.iter().position(|k| k == kind);
+
+        let text = match issue.help() {
+            Some(help) => format!("{}: {}; {help}", issue.subject(), issue.message()),
+            None => format!("{}: {}", issue.subject(), issue.message()),
+        };
+
+        let mut result = json!({
+            "ruleId": kind.id(),
+            "ruleIndex": rule_index,
+            "level": Self::level(issue.severity()),
+            "message": { "text": text },
+        });
+
+        let mut locations = issue.locations().iter().map(Self::location);
+
+        let location = locations
+            .next()
+            .unwrap_or_else(|| Self::location(&self.subject_location(issue.subject())));
+        result["locations"] = json!([location]);
+
+        let related = Vec::from_iter(locations.enumerate().map(|(id, mut location)| {
+            location["id"] = json!(id);
+            location
+        }));
+
+        if !related.is_empty() {
+            result["relatedLocations"] = json!(related);
+        }
+
+        result
+    }
+
+    fn subject_location(&self, subject: &Subject) -> SourceLocation {
+        let workspace = self.audit_result.workspace();
+        let first_file_of = |locale: &Locale| {
+            workspace
+                .fluent_files_by_locale(locale)
+                .next()
+                .map(|file| file.path().to_path_buf())
+        };
+
+        let path = match subject {
+            Subject::FluentFile(path) | Subject::RustFile(path) => Some(path.clone()),
+            Subject::Locale(locale) | Subject::Entry(locale, _) => first_file_of(locale),
+            Subject::LanguageRoot(root) => workspace
+                .locales_by_language_root(root)
+                .find_map(first_file_of),
+        };
+
+        SourceLocation::start_of(path.as_deref().unwrap_or(&self.config_file))
+    }
+
+    fn level(severity: Severity) -> &'static str {
+        match severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "note",
+            Severity::Off => "none",
+        }
+    }
+
+    fn location(location: &SourceLocation) -> Value {
+        json!({
+            "physicalLocation": {
+                "artifactLocation": Self::artifact_location(location.path()),
+                "region": {
+                    "startLine": location.line(),
+                    "startColumn": location.column(),
+                }
+            }
+        })
+    }
+
+    fn artifact_location(path: &Path) -> Value {
+        let uri = Self::percent_encode(&path.to_string_lossy().replace('\\', "/"));
+
+        if path.is_absolute() {
+            json!({ "uri": format!("file:///{}", uri.trim_start_matches('/')) })
+        } else {
+            json!({ "uri": uri.trim_start_matches("./"), "uriBaseId": SOURCE_ROOT })
+        }
+    }
+
+    /// Percent-encodes every byte of the path that may not appear unescaped in a URI path,
+    /// leaving its `/` separators (and a Windows drive's `:`) as they are.
+    fn percent_encode(path: &str) -> String {
+        path.bytes().fold(String::new(), |mut uri, byte| {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => uri.push(byte as char),
+                b'-' | b'.' | b'_' | b'~' | b'/' | b':' => uri.push(byte as char),
+                _ => {
+                    let _ = write!(uri, "%{byte:02X}");
+                }
+            }
+            uri
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{audit::AuditEngine, config::LingoraToml, test_support::with_temp_fluent_files};
+
+    #[test]
+    fn will_render_issues_as_located_results_of_kind_rules() {
+        with_temp_fluent_files(
+            &[
+                ("en-GB", "message = Hello\nmessage = Hello again\n"),
+                ("it-IT", "other = Altro\n"),
+            ],
+            |files| {
+                let dir = files[0].path().parent().expect("temp dir");
+                let toml = format!(
+                    r#"
+[lingora]
+fluent_sources = [{:?}]
+canonical = "en-GB"
+primaries = ["it-IT"]
+
+[dioxus_i18n]
+rust_sources = []
+"#,
+                    dir.display().to_string()
+                );
+                let settings = LingoraToml::from_str(&toml).expect("valid settings");
+                let audit_result = AuditEngine::try_from(&settings)
+                    .and_then(|engine| engine.run())
+                    .expect("valid audit");
+
+                let mut out = Vec::new();
+                SarifRenderer::new(&audit_result)
+                    .render(&mut out)
+                    .expect("valid render");
+                let log: Value = serde_json::from_slice(&out).expect("valid json");
+
+                assert_eq!(log["version"], "2.1.0");
+
+                let run = &log["runs"][0];
+                let rules = run["tool"]["driver"]["rules"].as_array().expect("rules");
+                assert_eq!(rules.len(), Kind::ALL.len());
+
+                let results = run["results"].as_array().expect("results");
+                let rule_ids = Vec::from_iter(results.iter().map(|r| r["ruleId"].clone()));
+                assert_eq!(
+                    rule_ids,
+                    [
+                        "duplicate_identifier",
+                        "missing_translation",
+                        "redundant_translation"
+                    ]
+                );
+
+                let duplicate = &results[0];
+                let index = duplicate["ruleIndex"].as_u64().expect("rule index") as usize;
+                assert_eq!(rules[index]["id"], "duplicate_identifier");
+
+                let region = &duplicate["locations"][0]["physicalLocation"]["region"];
+                assert_eq!(
+                    (&region["startLine"], &region["startColumn"]),
+                    (&json!(1), &json!(1))
+                );
+
+                let related = &duplicate["relatedLocations"][0]["physicalLocation"]["region"];
+                assert_eq!(related["startLine"], 2);
+            },
+        );
+    }
+
+    #[test]
+    fn will_locate_results_without_a_file_at_the_config_file() {
+        with_temp_fluent_files(&[("en-GB", "message = Hello\n")], |files| {
+            let dir = files[0].path().parent().expect("temp dir");
+            let toml = format!(
+                r#"
+[lingora]
+fluent_sources = [{:?}]
+canonical = "en-GB"
+primaries = ["fr-FR"]
+
+[dioxus_i18n]
+rust_sources = []
+"#,
+                dir.display().to_string()
+            );
+            let settings = LingoraToml::from_str(&toml).expect("valid settings");
+            let audit_result = AuditEngine::try_from(&settings)
+                .and_then(|engine| engine.run())
+                .expect("valid audit");
+
+            let mut out = Vec::new();
+            SarifRenderer::new(&audit_result)
+                .with_config_file(Path::new("config/Lingora.toml"))
+                .render(&mut out)
+                .expect("valid render");
+            let log: Value = serde_json::from_slice(&out).expect("valid json");
+
+            let results = log["runs"][0]["results"].as_array().expect("results");
+            let locations = Vec::from_iter(results.iter().map(|r| {
+                (
+                    r["ruleId"].clone(),
+                    r["locations"][0]["physicalLocation"]["artifactLocation"]["uri"].clone(),
+                )
+            }));
+            assert_eq!(
+                locations,
+                [(json!("missing_base"), json!("config/Lingora.toml"))]
+            );
+        });
+    }
+
+    #[test]
+    fn will_default_rule_levels_to_kind_severities() {
+        let rule = SarifRenderer::rule(&Kind::DuplicateIdentifier);
+        assert_eq!(
+            rule["defaultConfiguration"]["level"],
+            SarifRenderer::level(Kind::DuplicateIdentifier.default_severity())
+        );
+
+        let levels = Vec::from_iter(
+            Kind::ALL
+                .iter()
+                .map(|kind| SarifRenderer::rule(kind)["defaultConfiguration"]["level"].clone()),
+        );
+        assert!(levels.iter().any(|level| level != "error"));
+    }
+
+    #[test]
+    fn will_make_relative_paths_relative_to_source_root() {
+        let location = SarifRenderer::artifact_location(Path::new("./i18n/en-GB.ftl"));
+        assert_eq!(
+            location,
+            json!({ "uri": "i18n/en-GB.ftl", "uriBaseId": SOURCE_ROOT })
+        );
+    }
+
+    #[test]
+    fn will_percent_encode_artifact_paths() {
+        let relative = SarifRenderer::artifact_location(Path::new("my i18n/#1/en-GB.ftl"));
+        assert_eq!(
+            relative,
+            json!({ "uri": "my%20i18n/%231/en-GB.ftl", "uriBaseId": SOURCE_ROOT })
+        );
+
+        let absolute = SarifRenderer::artifact_location(Path::new("/srv/100% app/en-GB.ftl"));
+        assert_eq!(
+            absolute,
+            json!({ "uri": "file:///srv/100%25%20app/en-GB.ftl" })
+        );
+    }
+}
@@ -12,6 +12,12 @@ impl RustFile {
     pub fn path(&self) -> &Path {
         self.0.as_path()
     }
+
+    /// Refers to the file by its path alone, without checking that it is a Rust file, e.g.
+    /// for source read from an editor's buffer rather than from the file itself.
+    pub(crate) fn unchecked(path: &Path) -> Self {
+        Self(path.to_path_buf())
+    }
 }
 
 impl TryFrom<&Path> for RustFile {
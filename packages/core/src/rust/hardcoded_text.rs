@@ -0,0 +1,331 @@
+use proc_macro2::{Delimiter, LineColumn, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
+use syn::{ExprMacro, LitStr, Macro, visit::Visit};
+
+/// The attributes whose values are shown to the user, and so should be translated.
+const VISIBLE_ATTRIBUTES: [&str; 4] = ["title", "placeholder", "alt", "aria-label"];
+
+/// A string literal used as a text node, or as the value of a user-visible attribute, in
+/// an `rsx!` call, e.g. `button { title: "Save", "Save" }`, including the literals that
+/// are the values of an expression, e.g. `title: if compact { "X" } else { "Close" }`.
+///
+/// Literals with no visible text other than punctuation, digits or interpolations, e.g.
+/// `"{count} / {total}"`, are not recorded.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HardcodedText {
+    text: String,
+    attribute: Option<String>,
+    #[serde(with = "super::line_column")]
+    pub(super) start: LineColumn,
+}
+
+impl HardcodedText {
+    /// The literal's value, e.g. `Save` or `Hello, {name}`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The user-visible attribute whose value is the literal, e.g. `title`, or `None` for
+    /// a text node.
+    pub fn attribute(&self) -> Option<&str> {
+        self.attribute.as_deref()
+    }
+}
+
+/// Collects the hardcoded text of the `rsx!` calls in a file.
+#[derive(Default)]
+pub(super) struct HardcodedTextVisitor {
+    pub(super) texts: Vec<HardcodedText>,
+}
+
+impl HardcodedTextVisitor {
+    fn handle_macro(&mut self, mac: &Macro) {
+        match mac.path.segments.last() {
+            Some(segment) if segment.ident == "rsx" => self.walk_body(&mac.tokens),
+            _ => {}
+        }
+    }
+
+    /// Walks the children and attributes of an element, or of the `rsx!` call itself.
+    fn walk_body(&mut self, tokens: &TokenStream) {
+        let tokens = Vec::from_iter(tokens.clone());
+        let mut in_attribute = false;
+        // The user-visible attribute whose value is being walked, if any...
+        let mut attribute = None;
+
+        for (index, token) in tokens.iter().enumerate() {
+            let previous = index.checked_sub(1).and_then(|i| tokens.get(i));
+            let next = tokens.get(index + 1);
+
+            match token {
+                TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                    in_attribute = false;
+                    attribute = None;
+                }
+
+                TokenTree::Punct(punct) if is_attribute_colon(punct, previous) => {
+                    in_attribute = true;
+                    attribute = previous
+                        .and_then(attribute_name)
+                        .filter(|name| VISIBLE_ATTRIBUTES.contains(&name.as_str()));
+                }
+
+                // An attribute name, e.g. `"aria-label": "Close"`...
+                TokenTree::Literal(_) if next.is_some_and(is_colon) => {}
+
+                TokenTree::Literal(literal) if !in_attribute => self.record(literal, None),
+
+                TokenTree::Literal(literal) if previous.is_some_and(is_colon) => {
+                    if let Some(attribute) = &attribute {
+                        self.record(literal, Some(attribute));
+                    }
+                }
+
+                TokenTree::Group(group)
+                    if !in_attribute
+                        && group.delimiter() == Delimiter::Brace
+                        && matches!(previous, Some(TokenTree::Ident(_))) =>
+                {
+                    self.walk_body(&group.stream());
+                }
+
+                TokenTree::Group(group) if is_rsx_call(&tokens, index) => {
+                    self.walk_body(&group.stream());
+                }
+
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                    match &attribute {
+                        Some(attribute) => self.walk_attribute_value(&group.stream(), attribute),
+                        None => self.walk_expression(&group.stream()),
+                    }
+                }
+
+                TokenTree::Group(group) => self.walk_expression(&group.stream()),
+
+                _ => {}
+            }
+        }
+    }
+
+    /// Walks a block within the value of a user-visible attribute, e.g. a branch of
+    /// `title: if compact { "X" } else { "Close" }`, for the literals that are its value,
+    /// i.e. those ending the block or a `match` arm, and for any nested `rsx!` calls.
+    fn walk_attribute_value(&mut self, tokens: &TokenStream, attribute: &str) {
+        let tokens = Vec::from_iter(tokens.clone());
+
+        for (index, token) in tokens.iter().enumerate() {
+            let previous = index.checked_sub(1).and_then(|i| tokens.get(i));
+            let next = tokens.get(index + 1);
+
+            match token {
+                TokenTree::Literal(literal)
+                    if previous.is_none_or(|p| is_punct(p, ';') || is_punct(p, '>'))
+                        && next.is_none_or(|n| is_punct(n, ',')) =>
+                {
+                    self.record(literal, Some(attribute));
+                }
+
+                TokenTree::Group(group) if is_rsx_call(&tokens, index) => {
+                    self.walk_body(&group.stream());
+                }
+
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                    self.walk_attribute_value(&group.stream(), attribute);
+                }
+
+                TokenTree::Group(group) => self.walk_expression(&group.stream()),
+
+                _ => {}
+            }
+        }
+    }
+
+    /// Records the literal, if it is a string with visible text.
+    fn record(&mut self, literal: &proc_macro2::Literal, attribute: Option<&str>) {
+        if let Ok(literal) = syn::parse2::<LitStr>(literal.to_token_stream())
+            && has_visible_text(&literal.value())
+        {
+            self.texts.push(HardcodedText {
+                text: literal.value(),
+                attribute: attribute.map(String::from),
+                start: literal.span().start(),
+            });
+        }
+    }
+
+    /// Walks a Rust expression within an `rsx!` call, e.g. `{ items.iter().map(...) }`,
+    /// for any nested `rsx!` calls.
+    fn walk_expression(&mut self, tokens: &TokenStream) {
+        let tokens = Vec::from_iter(tokens.clone());
+
+        for (index, token) in tokens.iter().enumerate() {
+            if let TokenTree::Group(group) = token {
+                if is_rsx_call(&tokens, index) {
+                    self.walk_body(&group.stream());
+                } else {
+                    self.walk_expression(&group.stream());
+                }
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for HardcodedTextVisitor {
+    fn visit_stmt_macro(&mut self, node: &'ast syn::StmtMacro) {
+        self.handle_macro(&node.mac);
+        syn::visit::visit_stmt_macro(self, node);
+    }
+
+    fn visit_item_macro(&mut self, node: &'ast syn::ItemMacro) {
+        self.handle_macro(&node.mac);
+        syn::visit::visit_item_macro(self, node);
+    }
+
+    fn visit_expr_macro(&mut self, node: &'ast ExprMacro) {
+        self.handle_macro(&node.mac);
+        syn::visit::visit_expr_macro(self, node);
+    }
+}
+
+/// Returns `true` if the group at `index` is the body of an `rsx!` call, however the macro's
+/// path is qualified, e.g. `dioxus::prelude::rsx!`, as only its last segment is compared.
+fn is_rsx_call(tokens: &[TokenTree], index: usize) -> bool {
+    match index.checked_sub(2).map(|i| &tokens[i..index]) {
+        Some([TokenTree::Ident(ident), TokenTree::Punct(bang)]) => {
+            ident == "rsx" && bang.as_char() == '!'
+        }
+        _ => false,
+    }
+}
+
+fn is_punct(token: &TokenTree, c: char) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == c)
+}
+
+fn is_colon(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ':' && punct.spacing() == Spacing::Alone)
+}
+
+/// Returns `true` if the punct is the `:` separating an attribute's name from its value,
+/// rather than part of a `::` path separator.
+fn is_attribute_colon(punct: &proc_macro2::Punct, previous: Option<&TokenTree>) -> bool {
+    punct.as_char() == ':'
+        && punct.spacing() == Spacing::Alone
+        && !matches!(previous, Some(TokenTree::Punct(p)) if p.as_char() == ':')
+}
+
+/// Returns the name of an attribute, e.g. `aria-label` for `aria_label` or `"aria-label"`.
+fn attribute_name(token: &TokenTree) -> Option<String> {
+    match token {
+        TokenTree::Ident(ident) => {
+            let name = ident.to_string();
+            Some(name.trim_start_matches("r#").replace('_', "-"))
+        }
+        TokenTree::Literal(literal) => syn::parse2::<LitStr>(literal.to_token_stream())
+            .ok()
+            .map(|literal| literal.value()),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the text, less its `{...}` interpolations, has any letters.
+fn has_visible_text(text: &str) -> bool {
+    let mut chars = text.chars().peekable();
+    let mut visible = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '{' => chars.by_ref().take_while(|c| *c != '}').for_each(drop),
+            c => visible |= c.is_alphabetic(),
+        }
+    }
+
+    visible
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn texts_of(source: &str) -> Vec<(String, Option<String>)> {
+        let file = syn::parse_file(source).expect("valid rust source");
+        let mut visitor = HardcodedTextVisitor::default();
+        visitor.visit_file(&file);
+
+        Vec::from_iter(
+            visitor
+                .texts
+                .into_iter()
+                .map(|text| (text.text, text.attribute)),
+        )
+    }
+
+    #[test]
+    fn text_nodes_and_visible_attributes_are_collected() {
+        let texts = texts_of(
+            r#"
+fn app() -> Element {
+    rsx! {
+        div { class: "container", id: "main",
+            h1 { "Welcome" }
+            input { placeholder: "Search", aria_label: "Search box" }
+            img { alt: "Logo", src: "logo.png" }
+            button { "aria-label": "Close", title: if compact { "X" } else { "Close" }, "×" }
+            p { "{count} / {total}" }
+            p { {t!("translated")} }
+            {items.iter().map(|item| rsx! { li { "Item {item}" } })}
+        }
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            texts,
+            [
+                ("Welcome".into(), None),
+                ("Search".into(), Some("placeholder".into())),
+                ("Search box".into(), Some("aria-label".into())),
+                ("Logo".into(), Some("alt".into())),
+                ("Close".into(), Some("aria-label".into())),
+                ("X".into(), Some("title".into())),
+                ("Close".into(), Some("title".into())),
+                ("Item {item}".into(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn qualified_rsx_calls_and_attribute_expressions_are_walked() {
+        let texts = texts_of(
+            r#"
+fn app() -> Element {
+    dioxus::prelude::rsx! {
+        span {
+            title: match state {
+                State::Idle => "Idle",
+                _ if busy => { let _ = log(); "Busy" }
+                _ => t!("state-other"),
+            },
+            alt: if name == "logo" { name } else { format!("Image {name}") },
+            {items.iter().map(|item| dioxus::prelude::rsx! { li { "Item {item}" } })}
+        }
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            texts,
+            [
+                ("Idle".into(), Some("title".into())),
+                ("Busy".into(), Some("title".into())),
+                ("Item {item}".into(), None),
+            ]
+        );
+    }
+}
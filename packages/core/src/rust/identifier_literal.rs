@@ -0,0 +1,267 @@
+use std::{path::Path, str::FromStr, sync::LazyLock};
+
+use regex::Regex;
+
+use crate::{
+    config::{LingoraToml, RustScanSettings},
+    domain::SourceLocation,
+    fluent::QualifiedIdentifier,
+    rust::{ParsedRustFile, RustFile},
+};
+
+/// Matches the start of what may be a call of a translation macro, function or method,
+/// e.g. `t!(`, `i18n::lookup(` or `.lookup(`.
+static CALL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?P<method>\.\s*)?(?P<path>(?:::)?[A-Za-z_]\w*(?:\s*::\s*[A-Za-z_]\w*)*)\s*(?P<bang>!)?\s*(?P<open>[(\[{])",
+    )
+    .expect("required valid regex for call")
+});
+
+/// A string literal passed as the identifier to one of the scanned translation macros,
+/// functions or methods, e.g. `"greeting"` in `t!("greeting")`, with its extent in the
+/// source, quotes included.
+///
+/// Used by editor integrations to relate a position in a Rust source to the Fluent
+/// identifier there, e.g. to go to its definition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifierLiteral {
+    literal: String,
+    start: SourceLocation,
+    end: SourceLocation,
+}
+
+impl IdentifierLiteral {
+    /// Finds the identifier literals in the given source of a Rust file, which need not
+    /// have been saved.
+    ///
+    /// If the source does not parse, e.g. while a call is part way through being typed,
+    /// the calls are found by scanning its text instead, and a literal missing its closing
+    /// quote extends as far as it is an identifier, as though it were closed there.
+    ///
+    /// Identifiers passed through constants, or built by `format!` calls, are not
+    /// literals, and are not found.
+    pub fn find_all(settings: &LingoraToml, path: &Path, source: &str) -> Vec<Self> {
+        let file = ParsedRustFile::parse_source(
+            &RustFile::unchecked(path),
+            source,
+            &settings.rust_scan,
+            false,
+        );
+
+        if file.has_syntax_error() {
+            return Self::scan(&settings.rust_scan, path, source);
+        }
+
+        Vec::from_iter(
+            file.macro_calls()
+                .filter(|call| call.constant().is_none())
+                .filter_map(|call| {
+                    let literal = call.literal()?.to_string();
+                    let end = file.end_location(call)?;
+                    let start = file.location(call);
+                    Some(Self {
+                        literal,
+                        start,
+                        end,
+                    })
+                }),
+        )
+    }
+
+    /// Finds the literals by matching the calls in the text of the source, and then the
+    /// arguments of each, allowing for nested groups and strings, but not comments.
+    fn scan(settings: &RustScanSettings, path: &Path, source: &str) -> Vec<Self> {
+        Vec::from_iter(CALL.captures_iter(source).filter_map(|captures| {
+            let name = String::from_iter(captures["path"].split_whitespace());
+            let open = captures.name("open")?;
+            let is_call = open.as_str() == "(";
+
+            let position = match (captures.name("method"), captures.name("bang")) {
+                (_, Some(_)) => settings.macro_argument(&name),
+                (Some(_), None) if is_call => settings.method_argument(&name),
+                (None, None) if is_call => settings.function_argument(&name),
+                _ => None,
+            }?;
+
+            Self::scan_argument(path, source, open.end(), position)
+        }))
+    }
+
+    /// Scans the arguments following the opening delimiter at `offset` for a literal at
+    /// `position`.
+    fn scan_argument(path: &Path, source: &str, offset: usize, position: usize) -> Option<Self> {
+        let mut chars = source[offset..]
+            .char_indices()
+            .map(|(i, c)| (offset + i, c))
+            .peekable();
+
+        let (mut depth, mut commas) = (0, 0);
+        while commas < position {
+            match chars.next()? {
+                (_, '(' | '[' | '{') => depth += 1,
+                (_, ')' | ']' | '}') if depth == 0 => return None,
+                (_, ')' | ']' | '}') => depth -= 1,
+                (_, ',') if depth == 0 => commas += 1,
+                (_, '"') => {
+                    let mut escaped = false;
+                    chars.find(|(_, c)| {
+                        let closes = *c == '"' && !escaped;
+                        escaped = *c == '\\' && !escaped;
+                        closes
+                    })?;
+                }
+                _ => {}
+            }
+        }
+
+        while chars
+            .next_if(|(_, c)| c.is_whitespace() || *c == '&')
+            .is_some()
+        {}
+
+        let (start, _) = chars.next_if(|(_, c)| *c == '"')?;
+        let is_identifier = |c: &char| c.is_alphanumeric() || matches!(c, '-' | '_' | '.');
+        let literal = String::from_iter(
+            std::iter::from_fn(|| chars.next_if(|(_, c)| is_identifier(c))).map(|(_, c)| c),
+        );
+
+        let end = match chars.next() {
+            Some((closing, '"')) => SourceLocation::from_offset(path, source, closing + 1),
+            Some((_, '\n')) | None => {
+                let end = start + 1 + literal.len();
+                let end = SourceLocation::from_offset(path, source, end);
+                SourceLocation::new(path, end.line(), end.column() + 1)
+            }
+            Some(_) => return None,
+        };
+
+        Some(Self {
+            literal,
+            start: SourceLocation::from_offset(path, source, start),
+            end,
+        })
+    }
+
+    /// The literal's value, e.g. `greeting.title`.
+    pub fn literal(&self) -> &str {
+        &self.literal
+    }
+
+    /// The Fluent identifier named by the literal, if it is a valid identifier.
+    pub fn identifier(&self) -> Option<QualifiedIdentifier> {
+        QualifiedIdentifier::from_str(&self.literal).ok()
+    }
+
+    /// The location of the literal's opening quote.
+    pub fn start(&self) -> &SourceLocation {
+        &self.start
+    }
+
+    /// The location just past the literal's closing quote.
+    pub fn end(&self) -> &SourceLocation {
+        &self.end
+    }
+
+    /// Returns `true` if the one-based line and column are within the literal's quotes,
+    /// including just inside either quote.
+    pub fn contains(&self, line: usize, column: usize) -> bool {
+        let after_start = (line, column) > (self.start.line(), self.start.column());
+        let before_end = (line, column) < (self.end.line(), self.end.column());
+        after_start && before_end
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn literals_in_identifier_position_are_found() {
+        let settings = LingoraToml::from_str("").expect("valid settings");
+        let source = r#"
+fn app() {
+    let greeting = t!("greeting");
+    let title = t!("greeting.title", name: name);
+    let constant = t!(TITLE_KEY);
+    let dynamic = t!(&format!("status-{}", s));
+    let other = format!("greeting");
+}
+"#;
+
+        let literals = IdentifierLiteral::find_all(&settings, Path::new("app.rs"), source);
+
+        let found = Vec::from_iter(literals.iter().map(|literal| {
+            (
+                literal.literal(),
+                literal.start().to_string(),
+                literal.end().to_string(),
+            )
+        }));
+        assert_eq!(
+            found,
+            [
+                ("greeting", "app.rs:3:23".into(), "app.rs:3:33".into()),
+                ("greeting.title", "app.rs:4:20".into(), "app.rs:4:36".into()),
+            ]
+        );
+
+        assert!(!literals[0].contains(3, 23));
+        assert!(literals[0].contains(3, 24));
+        assert!(literals[0].contains(3, 32));
+        assert!(!literals[0].contains(3, 33));
+    }
+
+    #[test]
+    fn literals_are_located_alike_however_often_the_source_is_parsed() {
+        let settings = LingoraToml::from_str("").expect("valid settings");
+        let source = "fn app() {\n    let greeting = t!(\"greeting\");\n}\n";
+
+        let first = IdentifierLiteral::find_all(&settings, Path::new("app.rs"), source);
+        for _ in 0..100 {
+            let next = IdentifierLiteral::find_all(&settings, Path::new("app.rs"), source);
+            assert_eq!(next, first);
+        }
+        assert_eq!(first[0].start().to_string(), "app.rs:2:23");
+    }
+
+    #[test]
+    fn literals_are_found_by_scanning_source_that_does_not_parse() {
+        let settings = LingoraToml::from_str(
+            r#"
+[rust_scan]
+macros = [{ name = "t" }, { name = "fl", argument = 1 }]
+functions = [{ name = "lookup", argument = 1 }]
+"#,
+        )
+        .expect("valid settings");
+        let source = r#"
+fn app() {
+    let greeting = t!("greeting" }
+    let title = fl!(loader, "greeting.title");
+    let other = LOCALES.lookup(&(a, b), "other");
+    let text = format!("not-an-identifier");
+    let farewell = t!("fare
+"#;
+
+        let literals = IdentifierLiteral::find_all(&settings, Path::new("app.rs"), source);
+
+        let found = Vec::from_iter(literals.iter().map(|literal| {
+            (
+                literal.literal(),
+                literal.start().to_string(),
+                literal.end().to_string(),
+            )
+        }));
+        assert_eq!(
+            found,
+            [
+                ("greeting", "app.rs:3:23".into(), "app.rs:3:33".into()),
+                ("greeting.title", "app.rs:4:29".into(), "app.rs:4:45".into()),
+                ("other", "app.rs:5:41".into(), "app.rs:5:48".into()),
+                ("fare", "app.rs:7:23".into(), "app.rs:7:29".into()),
+            ]
+        );
+        assert!(literals[3].contains(7, 28));
+    }
+}
@@ -0,0 +1,95 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A glob, e.g. `status-*`, matching the identifiers that a `format!` string in identifier
+/// position may produce, e.g. `format!("status-{}", s)`. Each `{...}` placeholder becomes a
+/// `*`, which matches any text.
+///
+/// Serialized as the literal pieces between the placeholders, from which the pattern is
+/// rebuilt.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(into = "Vec<String>", from = "Vec<String>")]
+pub struct IdentifierPattern {
+    pieces: Vec<String>,
+    regex: Regex,
+}
+
+impl IdentifierPattern {
+    /// Derives the pattern from the format string of a `format!` call.
+    pub fn from_format_string(format: &str) -> Self {
+        let mut pieces = vec![String::new()];
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    pieces.last_mut().expect("at least one piece").push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    pieces.last_mut().expect("at least one piece").push('}');
+                }
+                '{' => {
+                    chars.by_ref().take_while(|c| *c != '}').for_each(drop);
+                    pieces.push(String::new());
+                }
+                c => pieces.last_mut().expect("at least one piece").push(c),
+            }
+        }
+
+        Self::from(pieces)
+    }
+
+    /// Returns `true` if the pattern matches the identifier literal, e.g. `status-active`.
+    pub fn matches(&self, literal: &str) -> bool {
+        self.regex.is_match(literal)
+    }
+}
+
+impl From<Vec<String>> for IdentifierPattern {
+    fn from(pieces: Vec<String>) -> Self {
+        let regex = Vec::from_iter(pieces.iter().map(|piece| regex::escape(piece))).join(".*");
+        let regex =
+            Regex::new(&format!("^{regex}$")).expect("required valid regex for identifier pattern");
+
+        Self { pieces, regex }
+    }
+}
+
+impl From<IdentifierPattern> for Vec<String> {
+    fn from(pattern: IdentifierPattern) -> Self {
+        pattern.pieces
+    }
+}
+
+impl std::fmt::Display for IdentifierPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pieces.join("*").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn placeholders_will_match_any_text() {
+        let pattern = IdentifierPattern::from_format_string("status-{}-{kind:?}");
+
+        assert_eq!(pattern.to_string(), "status-*-*");
+        assert!(pattern.matches("status-active-icon"));
+        assert!(pattern.matches("status--"));
+        assert!(!pattern.matches("state-active-icon"));
+        assert!(!pattern.matches("status-active"));
+    }
+
+    #[test]
+    fn escaped_braces_will_match_literally() {
+        let pattern = IdentifierPattern::from_format_string("lang.{{{}}}");
+
+        assert_eq!(pattern.to_string(), "lang.{*}");
+        assert!(pattern.matches("lang.{en}"));
+        assert!(!pattern.matches("lang.en"));
+    }
+}
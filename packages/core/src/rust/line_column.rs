@@ -0,0 +1,40 @@
+//! Serializes the `LineColumn` of a span, which does not implement serde's traits, as a
+//! `(line, column)` pair, for use with `#[serde(with = "line_column")]`.
+
+use proc_macro2::LineColumn;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub(super) fn serialize<S: Serializer>(
+    value: &LineColumn,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    (value.line, value.column).serialize(serializer)
+}
+
+pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<LineColumn, D::Error> {
+    let (line, column) = <(usize, usize)>::deserialize(deserializer)?;
+    Ok(LineColumn { line, column })
+}
+
+/// As above, for an optional `LineColumn`, with `#[serde(with = "line_column::optional")]`.
+pub(super) mod optional {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<LineColumn>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .map(|value| (value.line, value.column))
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<LineColumn>, D::Error> {
+        let value = Option::<(usize, usize)>::deserialize(deserializer)?;
+        Ok(value.map(|(line, column)| LineColumn { line, column }))
+    }
+}
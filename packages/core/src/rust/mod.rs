@@ -1,5 +1,13 @@
 mod file;
+mod hardcoded_text;
+mod identifier_literal;
+mod identifier_pattern;
+mod line_column;
 mod parsed_rust_file;
 
 pub use file::RustFile;
-pub use parsed_rust_file::ParsedRustFile;
+pub use hardcoded_text::HardcodedText;
+pub use identifier_literal::IdentifierLiteral;
+pub use identifier_pattern::IdentifierPattern;
+pub(crate) use parsed_rust_file::RustFileContent;
+pub use parsed_rust_file::{MacroCall, ParsedRustFile};
@@ -1,33 +1,184 @@
-use std::{fs, path::Path};
-
-use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use proc_macro2::{Delimiter, LineColumn, Spacing, Span, TokenStream, TokenTree};
 use quote::ToTokens;
-use syn::{Error as SynError, ExprMacro, LitStr, Macro, visit::Visit};
-
-use crate::{error::LingoraError, rust::RustFile};
-
-#[derive(Debug)]
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use syn::{
+    AttrStyle, Attribute, Error as SynError, Expr, ExprCall, ExprLit, ExprMacro, ExprMethodCall,
+    Lit, LitStr, Macro, Meta, Token, punctuated::Punctuated, spanned::Spanned, visit::Visit,
+};
+
+use crate::{
+    audit::Suppression,
+    config::RustScanSettings,
+    domain::SourceLocation,
+    error::LingoraError,
+    rust::{HardcodedText, IdentifierPattern, RustFile, hardcoded_text::HardcodedTextVisitor},
+};
+
+/// Matches a suppression comment, e.g. `// lingora:allow(undefined_identifier_literal)`.
+static ALLOW_COMMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<code>.*?)//[ ]*lingora:allow(?:[ ]*\((?P<kinds>[^)]*)\))?[ ]*$")
+        .expect("required valid regex for allow comment")
+});
+
+/// A call of a translation macro, function or method.
+///
+/// The identifier argument is usually a string literal, but may be a path, e.g. to a
+/// `const` or `static` string item, which is resolved once all files are parsed, or a
+/// `format!` call, from which an `IdentifierPattern` is derived. Calls with any other
+/// expression are recorded with that expression, as their identifier cannot be verified.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MacroCall {
     macro_name: String,
-    literal: String,
+    literal: Option<String>,
+    constant: Option<String>,
+    pattern: Option<IdentifierPattern>,
+    expression: Option<String>,
+    arguments: Option<Vec<String>>,
+    #[serde(with = "super::line_column")]
+    start: LineColumn,
+    #[serde(with = "super::line_column::optional")]
+    end: Option<LineColumn>,
+}
+
+impl MacroCall {
+    pub fn macro_name(&self) -> &str {
+        &self.macro_name
+    }
+
+    /// The identifier literal, given directly or through a resolved constant; `None` if
+    /// the constant cannot be resolved, or the identifier is a pattern.
+    pub fn literal(&self) -> Option<&str> {
+        self.literal.as_deref()
+    }
+
+    /// The path of the constant through which the identifier was passed, e.g. `TITLE_KEY`
+    /// in `t!(TITLE_KEY)`, if any.
+    pub fn constant(&self) -> Option<&str> {
+        self.constant.as_deref()
+    }
+
+    /// The pattern of the identifiers built by a `format!` call, e.g. `status-*` for
+    /// `t!(&format!("status-{}", s))`, if any.
+    pub fn pattern(&self) -> Option<&IdentifierPattern> {
+        self.pattern.as_ref()
+    }
+
+    /// The expression passed as the identifier, if it is not a literal, path or `format!`
+    /// call with a literal format string, e.g. `keys[0]` in `t!(&keys[0])`.
+    pub fn expression(&self) -> Option<&str> {
+        self.expression.as_deref()
+    }
+
+    /// The names of the named arguments passed after the literal, e.g. `count` in
+    /// `t!("emails", count: n)`, in call order; `None` for function and method calls,
+    /// whose arguments are not named in the call.
+    pub fn arguments(&self) -> Option<impl Iterator<Item = &str>> {
+        self.arguments
+            .as_ref()
+            .map(|arguments| arguments.iter().map(String::as_str))
+    }
 }
 
 impl MacroCall {
-    pub fn literal(&self) -> &str {
-        &self.literal
+    /// Resolves the identifier passed through a constant, by the constant's name alone,
+    /// as the `use` declarations that bring it into scope are not followed.
+    fn resolve(&mut self, constants: &Constants) {
+        if self.literal.is_none()
+            && let Some(constant) = &self.constant
+        {
+            let name = constant.rsplit("::").next().unwrap_or(constant);
+            self.literal = constants.value(name).map(String::from);
+        }
     }
 }
 
 impl std::fmt::Display for MacroCall {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}::{}", self.macro_name, self.literal)
+        let pattern = self.pattern.as_ref().map(|pattern| pattern.to_string());
+        let identifier = self
+            .literal
+            .as_deref()
+            .or(self.constant.as_deref())
+            .or(pattern.as_deref())
+            .or(self.expression.as_deref())
+            .unwrap_or("?");
+        write!(f, "{}::{}", self.macro_name, identifier)
+    }
+}
+
+/// The `const` and `static` string items, by name; a name defined with differing values
+/// is ambiguous, and resolves to nothing.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Constants(HashMap<String, Option<String>>);
+
+impl Constants {
+    fn insert(&mut self, name: String, value: Option<String>) {
+        self.0
+            .entry(name)
+            .and_modify(|existing| {
+                if *existing != value {
+                    *existing = None
+                }
+            })
+            .or_insert(value);
+    }
+
+    fn extend(&mut self, other: &Constants) {
+        other
+            .0
+            .iter()
+            .for_each(|(name, value)| self.insert(name.clone(), value.clone()));
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.0.get(name).and_then(|value| value.as_deref())
+    }
+}
+
+/// What is found by parsing a Rust file's source, as held by a `ParseCache` so that an
+/// unchanged file need not be parsed again.
+///
+/// Only files without a syntax error have content, as the error cannot be serialized.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct RustFileContent {
+    macro_calls: Vec<MacroCall>,
+    constants: Constants,
+    hardcoded_texts: Vec<HardcodedText>,
+    suppressions: Vec<Suppression>,
+}
+
+/// A syntax error of a file, with its span taken as a line and column, so that the spans of
+/// the parsed source can be released as soon as the file has been parsed.
+#[derive(Clone, Debug)]
+struct SyntaxError {
+    start: LineColumn,
+    description: String,
+}
+
+impl From<SynError> for SyntaxError {
+    fn from(error: SynError) -> Self {
+        Self {
+            start: error.span().start(),
+            description: error.to_string(),
+        }
     }
 }
 
 pub struct ParsedRustFile {
     file: RustFile,
-    syntax_error: Option<SynError>,
+    syntax_error: Option<SyntaxError>,
     macro_calls: Vec<MacroCall>,
+    constants: Constants,
+    hardcoded_texts: Vec<HardcodedText>,
+    suppressions: Vec<Suppression>,
 }
 
 impl ParsedRustFile {
@@ -47,124 +198,759 @@ impl ParsedRustFile {
         self.macro_calls.iter()
     }
 
+    /// The string literals used as text, or user-visible attribute values, in `rsx!` calls.
+    pub fn hardcoded_texts(&self) -> impl Iterator<Item = &HardcodedText> {
+        self.hardcoded_texts.iter()
+    }
+
+    pub fn suppressions(&self) -> impl Iterator<Item = &Suppression> {
+        self.suppressions.iter()
+    }
+
+    pub fn error_location(&self) -> SourceLocation {
+        self.syntax_error
+            .as_ref()
+            .map(|e| Self::location_of(self.path(), e.start))
+            .unwrap_or_else(|| SourceLocation::start_of(self.path()))
+    }
+
+    pub fn location(&self, call: &MacroCall) -> SourceLocation {
+        Self::location_of(self.path(), call.start)
+    }
+
+    /// The location just past the call's identifier argument, or `None` if the call is
+    /// within an interpolated string literal, whose contents are not located.
+    pub fn end_location(&self, call: &MacroCall) -> Option<SourceLocation> {
+        call.end.map(|end| Self::location_of(self.path(), end))
+    }
+
+    pub fn text_location(&self, text: &HardcodedText) -> SourceLocation {
+        Self::location_of(self.path(), text.start)
+    }
+
+    fn location_of(path: &Path, start: LineColumn) -> SourceLocation {
+        // proc_macro2 lines are one-based, but columns are zero-based...
+        SourceLocation::new(path, start.line, start.column + 1)
+    }
+
     pub fn error_description(&self) -> String {
         self.syntax_error
             .as_ref()
-            .map(|e| e.to_string())
+            .map(|e| e.description.clone())
             .unwrap_or_default()
     }
 }
 
-impl TryFrom<&RustFile> for ParsedRustFile {
-    type Error = LingoraError;
+impl ParsedRustFile {
+    /// Parses the file, recording the calls of the translation macros named in `settings`,
+    /// and the hardcoded text of its `rsx!` calls if `hardcoded_text`.
+    pub(crate) fn parse(
+        file: &RustFile,
+        settings: &RustScanSettings,
+        hardcoded_text: bool,
+    ) -> Result<Self, LingoraError> {
+        let source = fs::read_to_string(file.path())?;
+        Ok(Self::parse_source(file, &source, settings, hardcoded_text))
+    }
 
-    fn try_from(file: &RustFile) -> Result<Self, Self::Error> {
+    /// Parses the given source of the file, e.g. the unsaved content of an editor's buffer.
+    pub(crate) fn parse_source(
+        file: &RustFile,
+        source: &str,
+        settings: &RustScanSettings,
+        hardcoded_text: bool,
+    ) -> Self {
         let file = file.clone();
-        let source = fs::read_to_string(file.path())?;
-        let syntax = syn::parse_file(&source);
+        let syntax = syn::parse_file(source);
 
         let mut macro_calls = Vec::new();
+        let mut constants = Constants::default();
+        let mut hardcoded_texts = Vec::new();
+        let mut suppressions = Vec::new();
 
         if let Ok(syntax) = &syntax {
-            let mut visitor = MacroCallVisitor::default();
+            let mut visitor = MacroCallVisitor::new(settings);
             visitor.visit_file(syntax);
             macro_calls.extend(visitor.macro_calls);
+
+            let mut visitor = ConstantVisitor::default();
+            visitor.visit_file(syntax);
+            constants = visitor.constants;
+            macro_calls
+                .iter_mut()
+                .for_each(|call| call.resolve(&constants));
+
+            if hardcoded_text {
+                let mut visitor = HardcodedTextVisitor::default();
+                visitor.visit_file(syntax);
+                hardcoded_texts.extend(visitor.texts);
+            }
+
+            let mut visitor = SuppressionVisitor::new(file.path(), source);
+            visitor.visit_file(syntax);
+            suppressions.extend(visitor.into_suppressions());
         };
 
-        let syntax_error = syntax.err();
+        let syntax_error = syntax.err().map(SyntaxError::from);
 
-        Ok(Self {
+        // Every parse adds the source to proc_macro2's span map for the thread, which is
+        // otherwise never freed; all spans have been taken as lines and columns by now.
+        proc_macro2::extra::invalidate_current_thread_spans();
+
+        Self {
             file,
             syntax_error,
             macro_calls,
+            constants,
+            hardcoded_texts,
+            suppressions,
+        }
+    }
+
+    /// Returns what was found in the file's source, or `None` if it has a syntax error;
+    /// taken before constants defined in other files are resolved, as those may change.
+    pub(crate) fn content(&self) -> Option<RustFileContent> {
+        self.syntax_error.is_none().then(|| RustFileContent {
+            macro_calls: self.macro_calls.clone(),
+            constants: self.constants.clone(),
+            hardcoded_texts: self.hardcoded_texts.clone(),
+            suppressions: self.suppressions.clone(),
         })
     }
+
+    /// Restores the file from what was found in its source when it was last parsed.
+    pub(crate) fn from_content(file: &RustFile, content: RustFileContent) -> Self {
+        let RustFileContent {
+            macro_calls,
+            constants,
+            hardcoded_texts,
+            suppressions,
+        } = content;
+
+        Self {
+            file: file.clone(),
+            syntax_error: None,
+            macro_calls,
+            constants,
+            hardcoded_texts,
+            suppressions,
+        }
+    }
+
+    /// Resolves the identifiers passed through constants defined in other files, e.g. those
+    /// brought into scope with a `use`, provided the constant's name is unambiguous across
+    /// all the files.
+    pub(crate) fn resolve_constants(files: &mut [ParsedRustFile]) {
+        let constants = files.iter().fold(Constants::default(), |mut acc, file| {
+            acc.extend(&file.constants);
+            acc
+        });
+
+        files.iter_mut().for_each(|file| {
+            file.macro_calls
+                .iter_mut()
+                .for_each(|call| call.resolve(&constants))
+        });
+    }
 }
 
-#[derive(Default)]
-struct MacroCallVisitor {
+struct MacroCallVisitor<'a> {
+    settings: &'a RustScanSettings,
     macro_calls: Vec<MacroCall>,
 }
 
-fn record_direct_macro_call(tokens: &TokenStream, macro_name: &str, out: &mut Vec<MacroCall>) {
-    if let Some(TokenTree::Literal(literal)) = tokens.clone().into_iter().next()
-        && let Ok(literal) = syn::parse2::<LitStr>(literal.into_token_stream())
-    {
-        let macro_name = String::from(macro_name);
-        let literal = literal.value();
+impl<'a> MacroCallVisitor<'a> {
+    fn new(settings: &'a RustScanSettings) -> Self {
+        Self {
+            settings,
+            macro_calls: Vec::new(),
+        }
+    }
+
+    fn handle_macro(&mut self, mac: &Macro) {
+        let path = path_of(&mac.path);
+
+        match self.settings.macro_argument(&path) {
+            Some(position) => record_direct_macro_call(
+                &mac.tokens,
+                &path,
+                position,
+                true,
+                None,
+                &mut self.macro_calls,
+            ),
+            None => {
+                record_literal_macro_calls(&mac.tokens, self.settings, None, &mut self.macro_calls)
+            }
+        }
+    }
+
+    fn handle_call<'ast>(
+        &mut self,
+        name: &str,
+        position: usize,
+        args: impl IntoIterator<Item = &'ast Expr>,
+    ) {
+        let Some(mut argument) = args.into_iter().nth(position) else {
+            return;
+        };
+        while let Expr::Reference(reference) = argument {
+            argument = &reference.expr;
+        }
+
+        let expression = || Some(expression_text(argument.to_token_stream()));
+        let (literal, constant, pattern, expression) = match argument {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(literal),
+                ..
+            }) => (Some(literal.value()), None, None, None),
+            Expr::Path(constant) => (None, Some(path_of(&constant.path)), None, None),
+            Expr::Macro(ExprMacro { mac, .. }) if is_format(&path_of(&mac.path)) => {
+                match format_pattern(&mac.tokens) {
+                    Some(pattern) => (None, None, Some(pattern), None),
+                    None => (None, None, None, expression()),
+                }
+            }
+            _ => (None, None, None, expression()),
+        };
 
-        out.push(MacroCall {
-            macro_name,
+        self.macro_calls.push(MacroCall {
+            macro_name: String::from(name),
             literal,
+            constant,
+            pattern,
+            expression,
+            arguments: None,
+            start: argument.span().start(),
+            end: Some(argument.span().end()),
         });
     }
 }
 
-fn record_literal_macro_calls(tokens: &TokenStream, out: &mut Vec<MacroCall>) {
-    let mut iter = tokens.clone().into_iter().peekable();
+fn is_comma(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ',')
+}
+
+/// Records a macro, function or method call with its identifier argument at `position`,
+/// along with the names of any `name: value` (or `name = value`) arguments that follow it,
+/// if `with_arguments`, i.e. for a macro.
+///
+/// Calls found within an interpolated string literal are re-tokenized, so their spans
+/// are relative to that literal; the `origin` is the span of the enclosing literal
+/// in the source file, and is used in preference.
+fn record_direct_macro_call(
+    tokens: &TokenStream,
+    macro_name: &str,
+    position: usize,
+    with_arguments: bool,
+    origin: Option<Span>,
+    out: &mut Vec<MacroCall>,
+) {
+    // Arguments are split on top-level commas; nested groups are single token trees,
+    // so their commas are not seen.
+    let tokens = Vec::from_iter(tokens.clone());
+    let arguments = Vec::from_iter(tokens.split(is_comma));
+
+    let mut argument = arguments.get(position).copied().unwrap_or_default();
+    while let [TokenTree::Punct(reference), rest @ ..] = argument
+        && reference.as_char() == '&'
+    {
+        argument = rest;
+    }
+
+    let Some(first) = argument.first() else {
+        return;
+    };
+
+    let expression = || Some(expression_text(argument.iter().cloned()));
+    let (literal, constant, pattern, expression) = match argument {
+        [TokenTree::Literal(literal)] => match syn::parse2::<LitStr>(literal.to_token_stream()) {
+            Ok(literal) => (Some(literal.value()), None, None, None),
+            Err(_) => (None, None, None, expression()),
+        },
+        [
+            ..,
+            TokenTree::Ident(_),
+            TokenTree::Punct(bang),
+            TokenTree::Group(group),
+        ] if bang.as_char() == '!' => {
+            let path = constant_path(&argument[..argument.len() - 2]);
+            match path
+                .filter(|path| is_format(path))
+                .and_then(|_| format_pattern(&group.stream()))
+            {
+                Some(pattern) => (None, None, Some(pattern), None),
+                None => (None, None, None, expression()),
+            }
+        }
+        _ => match constant_path(argument) {
+            Some(constant) => (None, Some(constant), None, None),
+            None => (None, None, None, expression()),
+        },
+    };
+
+    let macro_name = String::from(macro_name);
+    let start = origin.unwrap_or_else(|| first.span()).start();
+    let end = argument
+        .last()
+        .filter(|_| origin.is_none())
+        .map(|last| last.span().end());
+    let arguments = with_arguments.then(|| named_arguments(&arguments[position + 1..]));
+
+    out.push(MacroCall {
+        macro_name,
+        literal,
+        constant,
+        pattern,
+        expression,
+        arguments,
+        start,
+        end,
+    });
+}
+
+/// Returns the path of the argument, e.g. `keys::TITLE_KEY`, if it is only a path.
+fn constant_path(argument: &[TokenTree]) -> Option<String> {
+    let (TokenTree::Ident(first), mut rest) = (argument.first()?, &argument[1..]) else {
+        return None;
+    };
+
+    let mut segments = vec![first.to_string()];
+    while let [
+        TokenTree::Punct(colon),
+        TokenTree::Punct(second_colon),
+        TokenTree::Ident(segment),
+        tail @ ..,
+    ] = rest
+        && colon.as_char() == ':'
+        && colon.spacing() == Spacing::Joint
+        && second_colon.as_char() == ':'
+    {
+        segments.push(segment.to_string());
+        rest = tail;
+    }
+
+    rest.is_empty().then(|| segments.join("::"))
+}
+
+/// Returns the tokens as written, but for whitespace, which is kept only between words and
+/// after commas, e.g. `keys[0]` or `format!(template, key)`.
+fn expression_text(tokens: impl IntoIterator<Item = TokenTree>) -> String {
+    let mut text = String::new();
+    let mut after_word = false;
+
+    for token in tokens {
+        let is_word = matches!(token, TokenTree::Ident(_) | TokenTree::Literal(_));
+        if is_word && after_word {
+            text.push(' ');
+        }
+        after_word = is_word;
 
-    while let Some(token) = iter.next() {
         match token {
-            TokenTree::Ident(ident) if matches!(ident.to_string().as_str(), "t" | "te" | "tid") => {
-                let macro_name = ident.to_string();
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                text.push_str(open);
+                text.push_str(&expression_text(group.stream()));
+                text.push_str(close);
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ',' => text.push_str(", "),
+            token => text.push_str(&token.to_string()),
+        }
+    }
 
-                if let Some(TokenTree::Punct(punct)) = iter.peek()
-                    && punct.as_char() == '!'
-                {
-                    iter.next();
+    text
+}
+
+fn is_format(path: &str) -> bool {
+    matches!(path, "format" | "std::format" | "alloc::format")
+}
+
+/// Returns the pattern of the identifiers built by a `format!` call with the given
+/// arguments, provided its format string is a literal.
+fn format_pattern(tokens: &TokenStream) -> Option<IdentifierPattern> {
+    let Some(TokenTree::Literal(literal)) = tokens.clone().into_iter().next() else {
+        return None;
+    };
 
-                    if let Some(TokenTree::Group(group)) = iter.next()
-                        && group.delimiter() == Delimiter::Parenthesis
-                    {
-                        record_direct_macro_call(&group.stream(), &macro_name, out);
-                    }
+    syn::parse2::<LitStr>(literal.to_token_stream())
+        .ok()
+        .map(|literal| IdentifierPattern::from_format_string(&literal.value()))
+}
+
+fn path_of(path: &syn::Path) -> String {
+    Vec::from_iter(path.segments.iter().map(|s| s.ident.to_string())).join("::")
+}
+
+/// Returns the names of the `name: value`, or `name = value`, arguments.
+fn named_arguments(arguments: &[&[TokenTree]]) -> Vec<String> {
+    arguments
+        .iter()
+        .filter_map(|argument| match argument {
+            [TokenTree::Ident(name), TokenTree::Punct(separator), ..]
+                if matches!(separator.as_char(), ':' | '=')
+                    && separator.spacing() == Spacing::Alone =>
+            {
+                let name = name.to_string();
+                Some(String::from(name.trim_start_matches("r#")))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the path of the macro or function named by the identifier at `index`, e.g.
+/// `dioxus_i18n::t`, by walking back over any preceding `segment::` tokens.
+fn macro_path(tokens: &[TokenTree], index: usize) -> String {
+    let mut segments = Vec::new();
+    let mut index = index;
+
+    while let Some(TokenTree::Ident(ident)) = tokens.get(index) {
+        segments.push(ident.to_string());
+
+        match index.checked_sub(3).map(|i| &tokens[i..index]) {
+            Some(
+                [
+                    TokenTree::Ident(_),
+                    TokenTree::Punct(first),
+                    TokenTree::Punct(second),
+                ],
+            ) if first.as_char() == ':'
+                && first.spacing() == Spacing::Joint
+                && second.as_char() == ':' =>
+            {
+                index -= 3
+            }
+            _ => break,
+        }
+    }
+
+    segments.reverse();
+    segments.join("::")
+}
+
+fn record_literal_macro_calls(
+    tokens: &TokenStream,
+    settings: &RustScanSettings,
+    origin: Option<Span>,
+    out: &mut Vec<MacroCall>,
+) {
+    let tokens = Vec::from_iter(tokens.clone());
+    let mut index = 0;
+
+    while let Some(token) = tokens.get(index) {
+        match (token, tokens.get(index + 1), tokens.get(index + 2)) {
+            (TokenTree::Ident(_), Some(TokenTree::Punct(bang)), Some(TokenTree::Group(group)))
+                if bang.as_char() == '!' && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                let path = macro_path(&tokens, index);
+
+                if let Some(position) = settings.macro_argument(&path) {
+                    record_direct_macro_call(&group.stream(), &path, position, true, origin, out);
+                    index += 2;
                 }
             }
 
-            TokenTree::Group(group) => {
-                record_literal_macro_calls(&group.stream(), out);
+            (TokenTree::Ident(ident), Some(TokenTree::Group(group)), _)
+                if group.delimiter() == Delimiter::Parenthesis =>
+            {
+                let is_method = index
+                    .checked_sub(1)
+                    .and_then(|i| tokens.get(i))
+                    .is_some_and(|t| matches!(t, TokenTree::Punct(dot) if dot.as_char() == '.'));
+
+                let (name, position) = if is_method {
+                    let name = ident.to_string();
+                    let position = settings.method_argument(&name);
+                    (name, position)
+                } else {
+                    let path = macro_path(&tokens, index);
+                    let position = settings.function_argument(&path);
+                    (path, position)
+                };
+
+                if let Some(position) = position {
+                    record_direct_macro_call(&group.stream(), &name, position, false, origin, out);
+                    index += 1;
+                }
             }
 
-            TokenTree::Literal(literal) => {
+            (TokenTree::Group(group), _, _) => {
+                record_literal_macro_calls(&group.stream(), settings, origin, out);
+            }
+
+            (TokenTree::Literal(literal), _, _) => {
                 if let Ok(literal) = syn::parse2::<LitStr>(literal.to_token_stream())
                     && let Ok(tokens) = literal.value().parse::<TokenStream>()
                 {
-                    record_literal_macro_calls(&tokens, out);
+                    let origin = origin.or_else(|| Some(literal.span()));
+                    record_literal_macro_calls(&tokens, settings, origin, out);
                 }
             }
 
             _ => {}
         }
-    }
-}
 
-fn handle_macro(mac: &Macro, macro_calls: &mut Vec<MacroCall>) {
-    if let Some(ident) = mac.path.get_ident() {
-        let macro_name = ident.to_string();
-
-        match macro_name.as_str() {
-            "t" | "tid" | "te" => {
-                record_direct_macro_call(&mac.tokens, &macro_name, macro_calls);
-            }
-
-            _ => record_literal_macro_calls(&mac.tokens, macro_calls),
-        }
+        index += 1;
     }
 }
 
-impl<'ast> Visit<'ast> for MacroCallVisitor {
+impl<'ast> Visit<'ast> for MacroCallVisitor<'_> {
     fn visit_stmt_macro(&mut self, node: &'ast syn::StmtMacro) {
-        handle_macro(&node.mac, &mut self.macro_calls);
+        self.handle_macro(&node.mac);
         syn::visit::visit_stmt_macro(self, node);
     }
 
     fn visit_item_macro(&mut self, node: &'ast syn::ItemMacro) {
-        handle_macro(&node.mac, &mut self.macro_calls);
+        self.handle_macro(&node.mac);
         syn::visit::visit_item_macro(self, node);
     }
 
     fn visit_expr_macro(&mut self, node: &'ast ExprMacro) {
-        handle_macro(&node.mac, &mut self.macro_calls);
+        self.handle_macro(&node.mac);
         syn::visit::visit_expr_macro(self, node);
     }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let Expr::Path(function) = node.func.as_ref() {
+            let path = path_of(&function.path);
+
+            if let Some(position) = self.settings.function_argument(&path) {
+                self.handle_call(&path, position, &node.args);
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let name = node.method.to_string();
+
+        if let Some(position) = self.settings.method_argument(&name) {
+            self.handle_call(&name, position, &node.args);
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+}
+
+/// Collects the `const` and `static` items of the file whose value is a string literal,
+/// e.g. `const TITLE_KEY: &str = "page-title";`, wherever they are declared.
+#[derive(Default)]
+struct ConstantVisitor {
+    constants: Constants,
+}
+
+impl ConstantVisitor {
+    fn record(&mut self, ident: &syn::Ident, expr: &Expr) {
+        let mut expr = expr;
+        while let Expr::Reference(reference) = expr {
+            expr = &reference.expr;
+        }
+
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(literal),
+            ..
+        }) = expr
+        {
+            self.constants
+                .insert(ident.to_string(), Some(literal.value()));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for ConstantVisitor {
+    fn visit_item_const(&mut self, node: &'ast syn::ItemConst) {
+        self.record(&node.ident, &node.expr);
+        syn::visit::visit_item_const(self, node);
+    }
+
+    fn visit_item_static(&mut self, node: &'ast syn::ItemStatic) {
+        self.record(&node.ident, &node.expr);
+        syn::visit::visit_item_static(self, node);
+    }
+
+    fn visit_impl_item_const(&mut self, node: &'ast syn::ImplItemConst) {
+        self.record(&node.ident, &node.expr);
+        syn::visit::visit_impl_item_const(self, node);
+    }
+}
+
+/// A `// lingora:allow` comment, and the line of the item or statement to which it applies;
+/// its own line if it trails code, otherwise the next line of code, if any.
+struct AllowComment {
+    directive: SourceLocation,
+    kinds: Vec<String>,
+    target_line: usize,
+    claimed: bool,
+}
+
+/// Collects the suppressions of the file, from `#[lingora::allow(...)]` attributes
+/// (also recognised within `#[cfg_attr(..., lingora::allow(...))]`), and `// lingora:allow(...)`
+/// comments.
+///
+/// A suppression applies to the lines of the item, statement or expression to which it
+/// is attached; an inner attribute at the top of the file applies to the whole file.
+struct SuppressionVisitor {
+    path: PathBuf,
+    comments: Vec<AllowComment>,
+    enclosing: Vec<Span>,
+    suppressions: Vec<Suppression>,
+}
+
+impl SuppressionVisitor {
+    fn new(path: &Path, source: &str) -> Self {
+        let lines = Vec::from_iter(source.lines());
+        let is_code = |line: &&str| {
+            let line = line.trim();
+            !(line.is_empty() || line.starts_with("//"))
+        };
+
+        let comments = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let captures = ALLOW_COMMENT.captures(line)?;
+                let code = &captures["code"];
+                let target_line = if code.trim().is_empty() {
+                    lines[index + 1..]
+                        .iter()
+                        .position(is_code)
+                        .map_or(0, |offset| index + offset + 2)
+                } else {
+                    index + 1
+                };
+                let directive = SourceLocation::new(path, index + 1, code.chars().count() + 1);
+                let kinds = Suppression::parse_kinds(captures.name("kinds").map(|m| m.as_str()));
+                Some(AllowComment {
+                    directive,
+                    kinds,
+                    target_line,
+                    claimed: false,
+                })
+            })
+            .collect();
+
+        Self {
+            path: path.to_path_buf(),
+            comments,
+            enclosing: Vec::new(),
+            suppressions: Vec::new(),
+        }
+    }
+
+    fn into_suppressions(self) -> Vec<Suppression> {
+        // Comments not followed by an item or statement apply to nothing, and so are
+        // kept only to be reported as unused...
+        let unclaimed = self
+            .comments
+            .into_iter()
+            .filter(|comment| !comment.claimed)
+            .map(|comment| Suppression::lines(comment.directive, comment.kinds, 0..0));
+        self.suppressions.into_iter().chain(unclaimed).collect()
+    }
+
+    fn lines_of(span: Span) -> std::ops::Range<usize> {
+        span.start().line..span.end().line + 1
+    }
+
+    fn enter(&mut self, span: Span) {
+        let lines = Self::lines_of(span);
+        self.comments
+            .iter_mut()
+            .filter(|comment| !comment.claimed && comment.target_line == lines.start)
+            .for_each(|comment| {
+                comment.claimed = true;
+                self.suppressions.push(Suppression::lines(
+                    comment.directive.clone(),
+                    comment.kinds.clone(),
+                    lines.clone(),
+                ));
+            });
+        self.enclosing.push(span);
+    }
+
+    fn leave(&mut self) {
+        self.enclosing.pop();
+    }
+
+    fn is_allow(path: &syn::Path) -> bool {
+        let segments = Vec::from_iter(path.segments.iter().map(|s| s.ident.to_string()));
+        segments == ["lingora", "allow"]
+    }
+
+    /// Returns the kinds allowed by a `lingora::allow` meta; none meaning all kinds.
+    fn allowed_kinds(meta: &Meta) -> Option<Vec<String>> {
+        match meta {
+            Meta::Path(path) if Self::is_allow(path) => Some(Vec::new()),
+            Meta::List(list) if Self::is_allow(&list.path) => {
+                let kinds = list
+                    .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                    .ok()?;
+                Some(Vec::from_iter(
+                    kinds.iter().map(|kind| kind.to_token_stream().to_string()),
+                ))
+            }
+            Meta::List(list) if list.path.is_ident("cfg_attr") => list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()?
+                .iter()
+                .skip(1)
+                .find_map(Self::allowed_kinds),
+            _ => None,
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for SuppressionVisitor {
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        if let Some(kinds) = Self::allowed_kinds(&node.meta) {
+            let start = node.pound_token.span.start();
+            let directive = SourceLocation::new(&self.path, start.line, start.column + 1);
+            let suppression = match (self.enclosing.last(), node.style) {
+                (Some(span), _) => Suppression::lines(directive, kinds, Self::lines_of(*span)),
+                (None, AttrStyle::Inner(_)) => Suppression::file(directive, kinds),
+                (None, AttrStyle::Outer) => Suppression::lines(directive, kinds, 0..0),
+            };
+            self.suppressions.push(suppression);
+        }
+    }
+
+    fn visit_item(&mut self, node: &'ast syn::Item) {
+        self.enter(node.span());
+        syn::visit::visit_item(self, node);
+        self.leave();
+    }
+
+    fn visit_impl_item(&mut self, node: &'ast syn::ImplItem) {
+        self.enter(node.span());
+        syn::visit::visit_impl_item(self, node);
+        self.leave();
+    }
+
+    fn visit_trait_item(&mut self, node: &'ast syn::TraitItem) {
+        self.enter(node.span());
+        syn::visit::visit_trait_item(self, node);
+        self.leave();
+    }
+
+    fn visit_stmt(&mut self, node: &'ast syn::Stmt) {
+        self.enter(node.span());
+        syn::visit::visit_stmt(self, node);
+        self.leave();
+    }
+
+    fn visit_expr(&mut self, node: &'ast syn::Expr) {
+        self.enter(node.span());
+        syn::visit::visit_expr(self, node);
+        self.leave();
+    }
+
+    fn visit_arm(&mut self, node: &'ast syn::Arm) {
+        self.enter(node.span());
+        syn::visit::visit_arm(self, node);
+        self.leave();
+    }
 }
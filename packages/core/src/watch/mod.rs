@@ -0,0 +1,3 @@
+mod source_watcher;
+
+pub use source_watcher::SourceWatcher;
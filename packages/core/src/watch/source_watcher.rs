@@ -0,0 +1,180 @@
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError},
+    time::Duration,
+};
+
+use notify::{
+    Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher, event::ModifyKind,
+    recommended_watcher,
+};
+
+use crate::{config::LingoraToml, error::LingoraError};
+
+/// How long the sources must be unchanged before a change is reported, so that a burst of
+/// changes, e.g. an editor's save or a `git checkout`, is reported once.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// Watches the Fluent and Rust sources of a `Lingora.toml`, and the configuration file
+/// itself, reporting debounced changes so that the audit can be re-run.
+///
+/// Within the source directories only `.ftl` and `.rs` files are of interest, and only
+/// changes to their content; reading them, e.g. when re-running the audit, is not a change.
+/// Sources that do not exist when the watcher is created are not watched.
+pub struct SourceWatcher {
+    files: Vec<PathBuf>,
+    directories: Vec<PathBuf>,
+    events: Receiver<notify::Result<Event>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl SourceWatcher {
+    /// Starts watching the `fluent_sources` and `rust_sources` of the settings, and the
+    /// configuration file from which they were read, if any.
+    pub fn new(settings: &LingoraToml, config_file: Option<&Path>) -> Result<Self, LingoraError> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = recommended_watcher(sender)?;
+
+        let mut files = Vec::new();
+        let mut directories = Vec::new();
+
+        let paths = settings
+            .lingora
+            .fluent_sources
+            .iter()
+            .chain(settings.dioxus_i18n.rust_sources.iter())
+            .map(PathBuf::as_path)
+            .chain(config_file);
+
+        for path in paths {
+            let Ok(path) = fs::canonicalize(path) else {
+                continue;
+            };
+
+            if path.is_dir() {
+                watcher.watch(&path, RecursiveMode::Recursive)?;
+                directories.push(path);
+            } else if let Some(parent) = path.parent() {
+                // Editors often save by replacing the file, so its directory is watched.
+                watcher.watch(parent, RecursiveMode::NonRecursive)?;
+                files.push(path);
+            }
+        }
+
+        Ok(Self {
+            files,
+            directories,
+            events,
+            _watcher: watcher,
+        })
+    }
+
+    /// Blocks until any of the watched sources change, and have then been unchanged for a
+    /// short while, and returns the changed paths.
+    pub fn wait(&self) -> Result<Vec<PathBuf>, LingoraError> {
+        let mut changed = Vec::new();
+
+        while changed.is_empty() {
+            let event = self.events.recv().map_err(std::io::Error::other)?;
+            changed.extend(self.changed_paths(event?));
+        }
+
+        loop {
+            match self.events.recv_timeout(DEBOUNCE_TIMEOUT) {
+                Ok(event) => changed.extend(self.changed_paths(event?)),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(error @ RecvTimeoutError::Disconnected) => {
+                    return Err(std::io::Error::other(error).into());
+                }
+            }
+        }
+
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+
+    /// Returns the sources changed since last asked, if any, without blocking.
+    pub fn try_changes(&self) -> Result<Option<Vec<PathBuf>>, LingoraError> {
+        let mut changed = Vec::new();
+
+        loop {
+            match self.events.try_recv() {
+                Ok(event) => changed.extend(self.changed_paths(event?)),
+                Err(TryRecvError::Empty) => break,
+                Err(error @ TryRecvError::Disconnected) => {
+                    return Err(std::io::Error::other(error).into());
+                }
+            }
+        }
+
+        changed.sort();
+        changed.dedup();
+        Ok((!changed.is_empty()).then_some(changed))
+    }
+
+    fn changed_paths(&self, event: Event) -> Vec<PathBuf> {
+        match event.kind {
+            EventKind::Access(_) | EventKind::Modify(ModifyKind::Metadata(_)) => Vec::new(),
+            _ => Vec::from_iter(event.paths.into_iter().filter(|path| self.is_source(path))),
+        }
+    }
+
+    fn is_source(&self, path: &Path) -> bool {
+        let is_source_file = matches!(
+            path.extension().and_then(OsStr::to_str),
+            Some("ftl") | Some("rs")
+        );
+
+        self.files.iter().any(|file| file == path)
+            || (is_source_file && self.directories.iter().any(|dir| path.starts_with(dir)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        str::FromStr,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+
+    #[test]
+    fn changes_to_sources_will_be_reported() {
+        let dir = tempfile::TempDir::new().expect("temp dir");
+        let fluent_dir = dir.path().join("i18n");
+        let fluent_file = fluent_dir.join("en-GB.ftl");
+        let config_file = dir.path().join("Lingora.toml");
+        fs::create_dir(&fluent_dir).expect("fluent dir");
+        fs::write(&fluent_file, "greeting = Hello\n").expect("fluent file");
+        fs::write(&config_file, "").expect("config file");
+
+        let settings = LingoraToml::from_str(&format!(
+            "[lingora]\nfluent_sources = [{:?}]\n",
+            fluent_dir.display().to_string()
+        ))
+        .expect("valid settings");
+        let watcher = SourceWatcher::new(&settings, Some(&config_file)).expect("valid watcher");
+
+        fs::read_to_string(&fluent_file).expect("readable fluent file");
+        fs::write(fluent_dir.join("notes.txt"), "ignored").expect("other file");
+        fs::write(&fluent_file, "greeting = Hi\n").expect("fluent file");
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut changed = None;
+        while changed.is_none() && Instant::now() < deadline {
+            std::thread::sleep(DEBOUNCE_TIMEOUT);
+            changed = watcher.try_changes().expect("valid changes");
+        }
+
+        let fluent_file = fs::canonicalize(&fluent_file).expect("canonical fluent file");
+        assert_eq!(changed, Some(vec![fluent_file]));
+
+        fs::read_to_string(&config_file).expect("readable config file");
+        std::thread::sleep(DEBOUNCE_TIMEOUT);
+        assert_eq!(watcher.try_changes().expect("valid changes"), None);
+    }
+}
@@ -0,0 +1,484 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use lingora_core::prelude::*;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Diagnostic,
+    DiagnosticRelatedInformation, DiagnosticSeverity, Documentation, Hover, HoverContents,
+    Location, MarkupContent, MarkupKind, NumberOrString, Position, Range, TextEdit,
+};
+
+use crate::{error::LspError, text};
+
+/// The audit of a workspace folder, and the answers to the editor's questions about it.
+///
+/// Paths reported by the audit are relative to the folder's root, unless the settings gave
+/// them absolutely; paths given to, and returned from, the analysis are absolute. The Rust
+/// source of a query may be an editor's unsaved buffer, whereas the text of the files in
+/// which the audit's locations are placed, given by the `text_of` function passed to the
+/// query, should be as they were audited, i.e. as saved.
+pub struct Analysis {
+    root: PathBuf,
+    settings: LingoraToml,
+    audit_result: AuditResult,
+}
+
+impl Analysis {
+    /// Runs the audit for the settings of the folder at `root`, reusing and updating the
+    /// folder's cache of its parsed files.
+    ///
+    /// # Errors
+    /// Returns `LspError::Lingora` if the audit cannot be run, e.g. a source is missing.
+    pub fn new(
+        root: &Path,
+        settings: LingoraToml,
+        cache: &mut ParseCache,
+    ) -> Result<Self, LspError> {
+        let audit_result = AuditEngine::try_from(&settings)?.run_with_cache(cache)?;
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            settings,
+            audit_result,
+        })
+    }
+
+    /// The settings with which the audit was run.
+    pub fn settings(&self) -> &LingoraToml {
+        &self.settings
+    }
+
+    /// The issues as diagnostics, by the file in which each is first located.
+    ///
+    /// Issues without a location are reported at the start of the file of their subject,
+    /// i.e. the first Fluent file of their locale, or of their language root.
+    pub fn diagnostics<F>(&self, text_of: F) -> BTreeMap<PathBuf, Vec<Diagnostic>>
+    where
+        F: Fn(&Path) -> String,
+    {
+        let mut diagnostics = BTreeMap::<PathBuf, Vec<Diagnostic>>::new();
+
+        for issue in self.audit_result.issues() {
+            let mut locations = issue.locations().iter();
+            let Some(location) = locations
+                .next()
+                .cloned()
+                .or_else(|| self.subject_location(issue.subject()))
+            else {
+                continue;
+            };
+
+            let related_information = Vec::from_iter(locations.filter_map(|related| {
+                Some(DiagnosticRelatedInformation {
+                    location: self.location(related, &text_of)?,
+                    message: String::from("also here"),
+                })
+            }));
+
+            let path = text::resolve(&self.root, location.path());
+            let diagnostic = Diagnostic {
+                range: self.range(&location, &text_of(&path)),
+                severity: Some(severity(issue.severity())),
+                code: Some(NumberOrString::String(String::from(issue.kind().id()))),
+                source: Some(String::from("lingora")),
+                message: message(issue),
+                related_information: (!related_information.is_empty())
+                    .then_some(related_information),
+                ..Default::default()
+            };
+
+            diagnostics.entry(path).or_default().push(diagnostic);
+        }
+
+        diagnostics
+    }
+
+    /// The locations of the canonical definition of the identifier literal at the position
+    /// in the Rust source, if any.
+    pub fn definition<F>(
+        &self,
+        path: &Path,
+        source: &str,
+        position: Position,
+        text_of: F,
+    ) -> Vec<Location>
+    where
+        F: Fn(&Path) -> String,
+    {
+        let Some(identifier) = self
+            .literal_at(path, source, position)
+            .and_then(|literal| literal.identifier())
+        else {
+            return Vec::new();
+        };
+
+        self.canonical_document()
+            .into_iter()
+            .flat_map(|document| document.locations(&identifier))
+            .filter_map(|location| self.location(location, &text_of))
+            .collect()
+    }
+
+    /// The text of the message named by the identifier literal at the position in the Rust
+    /// source, in the canonical and primary locales.
+    pub fn hover(&self, path: &Path, source: &str, position: Position) -> Option<Hover> {
+        let literal = self.literal_at(path, source, position)?;
+        let root = literal.identifier()?.root();
+
+        let workspace = self.audit_result.workspace();
+        let locales = std::iter::once(workspace.canonical_locale())
+            .map(|locale| (locale, "canonical"))
+            .chain(
+                workspace
+                    .primary_locales()
+                    .map(|locale| (locale, "primary")),
+            );
+
+        let sections = Vec::from_iter(locales.map(|(locale, role)| {
+            let entries = self
+                .audit_result
+                .document(locale)
+                .into_iter()
+                .flat_map(|document| document.entries(&root))
+                .map(|entry| entry.to_string().trim_end().to_string());
+            let entries = Vec::from_iter(entries);
+
+            let text = if entries.is_empty() {
+                String::from("*missing*")
+            } else {
+                format!("```fluent\n{}\n```", entries.join("\n"))
+            };
+
+            format!("**{locale}** ({role})\n\n{text}")
+        }));
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: sections.join("\n\n"),
+            }),
+            range: Some(Range::new(
+                text::position(source, literal.start().line(), literal.start().column()),
+                text::position(source, literal.end().line(), literal.end().column()),
+            )),
+        })
+    }
+
+    /// The identifiers of the canonical document that can be looked up by the application,
+    /// if the position is within an identifier literal in the Rust source; each replaces
+    /// the literal's text.
+    pub fn completion(&self, path: &Path, source: &str, position: Position) -> Vec<CompletionItem> {
+        let Some(literal) = self.literal_at(path, source, position) else {
+            return Vec::new();
+        };
+        let Some(document) = self.canonical_document() else {
+            return Vec::new();
+        };
+
+        let range = Range::new(
+            text::position(source, literal.start().line(), literal.start().column() + 1),
+            text::position(source, literal.end().line(), literal.end().column() - 1),
+        );
+
+        let identifiers = BTreeSet::from_iter(document.public_identifiers());
+
+        identifiers
+            .iter()
+            .filter_map(|identifier| {
+                let label = identifier.to_literal_string()?;
+                let entry = document
+                    .entries(&identifier.root())
+                    .next()
+                    .map(|entry| entry.to_string().trim_end().to_string());
+
+                Some(CompletionItem {
+                    label: label.clone(),
+                    kind: Some(CompletionItemKind::TEXT),
+                    documentation: entry.map(|entry| {
+                        Documentation::MarkupContent(MarkupContent {
+                            kind: MarkupKind::Markdown,
+                            value: format!("```fluent\n{entry}\n```"),
+                        })
+                    }),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(range, label))),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    fn canonical_document(&self) -> Option<&AuditedDocument> {
+        self.audit_result
+            .document(self.audit_result.canonical_locale())
+    }
+
+    fn literal_at(
+        &self,
+        path: &Path,
+        source: &str,
+        position: Position,
+    ) -> Option<IdentifierLiteral> {
+        let (line, column) = text::line_column(source, position);
+
+        IdentifierLiteral::find_all(&self.settings, path, source)
+            .into_iter()
+            .find(|literal| literal.contains(line, column))
+    }
+
+    /// The location at which to report an issue about the subject, when the issue has no
+    /// location of its own.
+    fn subject_location(&self, subject: &Subject) -> Option<SourceLocation> {
+        let workspace = self.audit_result.workspace();
+        let first_file_of = |locale: &Locale| {
+            workspace
+                .fluent_files_by_locale(locale)
+                .next()
+                .map(|file| SourceLocation::start_of(file.path()))
+        };
+
+        match subject {
+            Subject::FluentFile(path) | Subject::RustFile(path) => {
+                Some(SourceLocation::start_of(path))
+            }
+            Subject::Locale(locale) | Subject::Entry(locale, _) => first_file_of(locale),
+            Subject::LanguageRoot(root) => workspace
+                .locales_by_language_root(root)
+                .find_map(first_file_of),
+        }
+    }
+
+    fn location<F>(&self, location: &SourceLocation, text_of: F) -> Option<Location>
+    where
+        F: Fn(&Path) -> String,
+    {
+        let path = text::resolve(&self.root, location.path());
+        let range = self.range(location, &text_of(&path));
+        text::uri_of(&path).map(|uri| Location::new(uri, range))
+    }
+
+    fn range(&self, location: &SourceLocation, text: &str) -> Range {
+        let (line, column) = (location.line(), location.column());
+        Range::new(
+            text::position(text, line, column),
+            text::position(text, line, text::token_end(text, line, column)),
+        )
+    }
+}
+
+fn severity(severity: Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Info | Severity::Off => DiagnosticSeverity::INFORMATION,
+    }
+}
+
+/// The issue's message, qualified by its subject unless that is the file in which it is
+/// reported, and followed by its help, if any.
+fn message(issue: &AuditIssue) -> String {
+    let message = match issue.subject() {
+        Subject::FluentFile(_) | Subject::RustFile(_) => issue.message().clone(),
+        Subject::Entry(locale, _) => format!("{locale}: {}", issue.message()),
+        subject => format!("{subject}: {}", issue.message()),
+    };
+
+    match issue.help() {
+        Some(help) => format!("{message}\nhelp: {help}"),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, str::FromStr};
+
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const APP: &str = r#"
+fn app() {
+    let greeting = t!("greeting");
+    let title = t!("greeting.title");
+    let farewell = t!("farewel");
+}
+"#;
+
+    fn with_analysis(test: impl FnOnce(&Analysis, &Path)) {
+        let root = TempDir::new().expect("temp dir");
+        let root = fs::canonicalize(root.path()).expect("canonical temp dir");
+        let i18n = root.join("i18n");
+        fs::create_dir_all(&i18n).expect("i18n dir");
+
+        fs::write(
+            i18n.join("en-GB.ftl"),
+            "greeting = Hello\n    .title = Greeting\nfarewell = Goodbye\n",
+        )
+        .expect("en-GB file");
+        fs::write(
+            i18n.join("fr-FR.ftl"),
+            "greeting = Bonjour\n    .title = Salut\n",
+        )
+        .expect("fr-FR file");
+        fs::write(root.join("app.rs"), APP).expect("rust file");
+
+        let toml = format!(
+            r#"
+[lingora]
+fluent_sources = [{:?}]
+canonical = "en-GB"
+primaries = ["fr-FR"]
+
+[dioxus_i18n]
+rust_sources = [{:?}]
+"#,
+            i18n.display().to_string(),
+            root.join("app.rs").display().to_string()
+        );
+        let settings = LingoraToml::from_str(&toml).expect("valid settings");
+        let analysis =
+            Analysis::new(&root, settings, &mut ParseCache::default()).expect("valid analysis");
+
+        test(&analysis, &root);
+    }
+
+    fn text_of(path: &Path) -> String {
+        fs::read_to_string(path).unwrap_or_default()
+    }
+
+    #[test]
+    fn issues_will_be_reported_as_diagnostics_in_their_files() {
+        with_analysis(|analysis, root| {
+            let diagnostics = analysis.diagnostics(text_of);
+
+            let summary = Vec::from_iter(diagnostics.iter().flat_map(|(path, diagnostics)| {
+                diagnostics.iter().map(move |diagnostic| {
+                    (
+                        path.strip_prefix(root).expect("in root").to_path_buf(),
+                        diagnostic.range,
+                        diagnostic.code.clone(),
+                        diagnostic.message.clone(),
+                    )
+                })
+            }));
+
+            let code = |id: &str| Some(NumberOrString::String(id.into()));
+            assert_eq!(
+                summary,
+                [
+                    (
+                        PathBuf::from("app.rs"),
+                        Range::new(Position::new(4, 22), Position::new(4, 31)),
+                        code("undefined_identifier_literal"),
+                        String::from(
+                            "identifier literal farewel is not defined in the canonical document\nhelp: did you mean 'farewell'?"
+                        ),
+                    ),
+                    (
+                        PathBuf::from("i18n/en-GB.ftl"),
+                        Range::new(Position::new(2, 0), Position::new(2, 8)),
+                        code("missing_translation"),
+                        String::from("fr-FR: missing translation 'farewell'"),
+                    ),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn literals_will_go_to_their_canonical_definition() {
+        with_analysis(|analysis, root| {
+            let path = root.join("app.rs");
+
+            let locations = analysis.definition(&path, APP, Position::new(2, 26), text_of);
+
+            let uri = text::uri_of(&root.join("i18n/en-GB.ftl")).expect("valid uri");
+            assert_eq!(
+                locations,
+                [Location::new(
+                    uri,
+                    Range::new(Position::new(0, 0), Position::new(0, 8))
+                )]
+            );
+
+            let locations = analysis.definition(&path, APP, Position::new(2, 8), text_of);
+            assert!(locations.is_empty());
+        });
+    }
+
+    #[test]
+    fn literals_will_show_their_canonical_and_primary_text_on_hover() {
+        with_analysis(|analysis, root| {
+            let path = root.join("app.rs");
+
+            let hover = analysis
+                .hover(&path, APP, Position::new(3, 25))
+                .expect("hover on literal");
+
+            let HoverContents::Markup(content) = hover.contents else {
+                panic!("expected markup");
+            };
+            assert_eq!(
+                content.value,
+                "**en-GB** (canonical)\n\n```fluent\ngreeting = Hello\n    .title = Greeting\n```\n\n\
+                 **fr-FR** (primary)\n\n```fluent\ngreeting = Bonjour\n    .title = Salut\n```"
+            );
+            assert_eq!(
+                hover.range,
+                Some(Range::new(Position::new(3, 19), Position::new(3, 35)))
+            );
+        });
+    }
+
+    #[test]
+    fn literals_will_complete_with_canonical_identifiers() {
+        with_analysis(|analysis, root| {
+            let path = root.join("app.rs");
+
+            let items = analysis.completion(&path, APP, Position::new(4, 25));
+
+            let labels = Vec::from_iter(items.iter().map(|item| item.label.as_str()));
+            assert_eq!(labels, ["farewell", "greeting", "greeting.title"]);
+
+            let range = Range::new(Position::new(4, 23), Position::new(4, 30));
+            assert_eq!(
+                items[0].text_edit,
+                Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    "farewell".into()
+                )))
+            );
+
+            assert!(
+                analysis
+                    .completion(&path, APP, Position::new(1, 0))
+                    .is_empty()
+            );
+        });
+    }
+
+    #[test]
+    fn literals_will_complete_while_the_call_is_being_typed() {
+        with_analysis(|analysis, root| {
+            let path = root.join("app.rs");
+            let source = "fn app() {\n    let farewell = t!(\"fare\n}\n";
+
+            let items = analysis.completion(&path, source, Position::new(1, 27));
+
+            let labels = Vec::from_iter(items.iter().map(|item| item.label.as_str()));
+            assert_eq!(labels, ["farewell", "greeting", "greeting.title"]);
+
+            let range = Range::new(Position::new(1, 23), Position::new(1, 27));
+            assert_eq!(
+                items[0].text_edit,
+                Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range,
+                    "farewell".into()
+                )))
+            );
+        });
+    }
+}
@@ -0,0 +1,25 @@
+use clap::Parser;
+use lingora_core::prelude::CoreArgs;
+
+/// Command-line arguments specific to the `lingora-lsp` language server.
+///
+/// The server takes only the shared `CoreArgs` (from `lingora-core`), which apply to each
+/// workspace folder opened by the editor. A relative `--config` file is found relative to
+/// each folder, as is `Lingora.toml` when no config file is given.
+#[derive(Debug, Parser)]
+#[command(
+    name = env!("CARGO_PKG_NAME"),
+    version = env!("CARGO_PKG_VERSION"),
+    about = env!("CARGO_PKG_DESCRIPTION"),
+)]
+pub struct LspArgs {
+    #[command(flatten)]
+    core_args: CoreArgs,
+}
+
+impl LspArgs {
+    /// Returns a reference to the shared core arguments.
+    pub fn core_args(&self) -> &CoreArgs {
+        &self.core_args
+    }
+}
@@ -0,0 +1,23 @@
+use lingora_core::prelude::LingoraError;
+use thiserror::Error;
+
+/// Main error type for the `lingora-lsp` binary.
+#[derive(Debug, Error)]
+pub enum LspError {
+    /// Any error that originated from `lingora-core`.
+    #[error(transparent)]
+    Lingora(#[from] LingoraError),
+
+    /// Low-level I/O error, e.g. the connection to the editor was lost.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// The editor did not follow the Language Server Protocol, e.g. it sent a request
+    /// before initializing the server.
+    #[error(transparent)]
+    Protocol(#[from] lsp_server::ProtocolError),
+
+    /// A message's parameters, or result, could not be (de)serialized.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
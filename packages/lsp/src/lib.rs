@@ -0,0 +1,14 @@
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(clippy::all)]
+#![doc = include_str!("../README.md")]
+
+mod analysis;
+mod args;
+mod error;
+mod server;
+mod text;
+
+pub use args::LspArgs;
+pub use error::LspError;
+pub use server::Server;
@@ -0,0 +1,17 @@
+#![feature(coverage_attribute)]
+
+use clap::Parser;
+use lingora_lsp::{LspArgs, LspError, Server};
+use lsp_server::Connection;
+
+#[coverage(off)]
+fn main() -> Result<(), LspError> {
+    let args = LspArgs::parse();
+
+    let (connection, io_threads) = Connection::stdio();
+    Server::new(args).run(&connection)?;
+    drop(connection);
+    io_threads.join()?;
+
+    Ok(())
+}
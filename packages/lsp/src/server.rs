@@ -0,0 +1,544 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crossbeam_channel::RecvTimeoutError;
+use lingora_core::prelude::{LingoraToml, ParseCache, SourceWatcher};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    CompletionOptions, CompletionResponse, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DidSaveTextDocumentParams, GotoDefinitionResponse,
+    HoverProviderCapability, InitializeParams, InitializeResult, LogMessageParams, MessageType,
+    OneOf, PublishDiagnosticsParams, ServerCapabilities, ServerInfo, TextDocumentPositionParams,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+        Exit, LogMessage, Notification as _, PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as _},
+};
+use serde::de::DeserializeOwned;
+
+use crate::{analysis::Analysis, args::LspArgs, error::LspError, text};
+
+/// How long to wait for a message from the editor before checking whether the sources
+/// have changed.
+const POLL_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// A workspace folder opened in the editor, with its latest audit, and the files parsed
+/// for it, so that re-audits parse only the files that have changed.
+struct Folder {
+    root: PathBuf,
+    analysis: Option<Analysis>,
+    cache: ParseCache,
+    watcher: Option<SourceWatcher>,
+    changes_pending: bool,
+    published: HashSet<PathBuf>,
+}
+
+impl Folder {
+    fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            analysis: None,
+            cache: ParseCache::default(),
+            watcher: None,
+            changes_pending: false,
+            published: HashSet::new(),
+        }
+    }
+}
+
+/// The language server, driven by messages from the editor over a `Connection`.
+///
+/// `Server`:
+/// - Audits each workspace folder, as `lingora-cli` would if run from the folder, and
+///   publishes the issues as diagnostics for the `.ftl` and `.rs` files in which they
+///   are located
+/// - Re-audits a folder when its sources, or its configuration file, change on disk; the
+///   diagnostics are those of the files as saved, and are placed in their saved text
+/// - Answers go-to-definition, hover and completion requests for the identifier literals
+///   of the scanned macros, using the editor's unsaved text of the Rust source
+pub struct Server {
+    args: LspArgs,
+    folders: Vec<Folder>,
+    documents: HashMap<PathBuf, String>,
+}
+
+impl Server {
+    /// Creates a server whose audits use the given arguments.
+    pub fn new(args: LspArgs) -> Self {
+        Self {
+            args,
+            folders: Vec::new(),
+            documents: HashMap::new(),
+        }
+    }
+
+    /// Initializes the connection and handles messages until the editor shuts the
+    /// server down, or the connection is closed.
+    ///
+    /// # Errors
+    /// Returns `LspError::Protocol` or `LspError::Json` if the editor does not initialize
+    /// the server properly, and `LspError::Io` if the connection fails. Failures in
+    /// auditing a folder, and notifications with malformed parameters, are logged to the
+    /// editor instead, while requests with malformed parameters are answered with an error.
+    pub fn run(mut self, connection: &Connection) -> Result<(), LspError> {
+        let (id, params) = connection.initialize_start()?;
+        let params = serde_json::from_value::<InitializeParams>(params)?;
+        connection.initialize_finish(id, serde_json::to_value(Self::initialize_result())?)?;
+
+        self.folders = Vec::from_iter(roots(&params).into_iter().map(Folder::new));
+        for index in 0..self.folders.len() {
+            self.audit(connection, index)?;
+        }
+
+        loop {
+            match connection.receiver.recv_timeout(POLL_TIMEOUT) {
+                Ok(Message::Request(request)) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(connection, request)?;
+                }
+                Ok(Message::Notification(notification)) if notification.method == Exit::METHOD => {
+                    return Ok(());
+                }
+                Ok(Message::Notification(notification)) => {
+                    self.handle_notification(connection, notification)?
+                }
+                Ok(Message::Response(_)) => {}
+                Err(RecvTimeoutError::Timeout) => self.poll_watchers(connection)?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+    }
+
+    fn initialize_result() -> InitializeResult {
+        let capabilities = ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Options(
+                TextDocumentSyncOptions {
+                    open_close: Some(true),
+                    change: Some(TextDocumentSyncKind::FULL),
+                    save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                    ..Default::default()
+                },
+            )),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec!["\"".into(), ".".into(), "-".into()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        InitializeResult {
+            capabilities,
+            server_info: Some(ServerInfo {
+                name: String::from(env!("CARGO_PKG_NAME")),
+                version: Some(String::from(env!("CARGO_PKG_VERSION"))),
+            }),
+        }
+    }
+
+    fn handle_request(
+        &mut self,
+        connection: &Connection,
+        request: Request,
+    ) -> Result<(), LspError> {
+        let response = match request.method.as_str() {
+            GotoDefinition::METHOD | HoverRequest::METHOD | Completion::METHOD => {
+                match serde_json::from_value::<TextDocumentPositionParams>(request.params) {
+                    Ok(params) => self.answer(request.id, &request.method, &params),
+                    Err(error) => Response::new_err(
+                        request.id,
+                        ErrorCode::InvalidParams as i32,
+                        format!("invalid parameters for '{}': {error}", request.method),
+                    ),
+                }
+            }
+            method => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request '{method}'"),
+            ),
+        };
+
+        send(connection, response)
+    }
+
+    /// Answers a go-to-definition, hover or completion request.
+    fn answer(
+        &self,
+        id: lsp_server::RequestId,
+        method: &str,
+        params: &TextDocumentPositionParams,
+    ) -> Response {
+        match method {
+            GotoDefinition::METHOD => {
+                let locations = self
+                    .query(params, |analysis, path, source, position| {
+                        analysis.definition(path, source, position, saved_text_of)
+                    })
+                    .unwrap_or_default();
+                let result =
+                    (!locations.is_empty()).then_some(GotoDefinitionResponse::Array(locations));
+                Response::new_ok(id, result)
+            }
+            HoverRequest::METHOD => {
+                let hover = self
+                    .query(params, |analysis, path, source, position| {
+                        analysis.hover(path, source, position)
+                    })
+                    .flatten();
+                Response::new_ok(id, hover)
+            }
+            _ => {
+                let items = self
+                    .query(params, |analysis, path, source, position| {
+                        analysis.completion(path, source, position)
+                    })
+                    .unwrap_or_default();
+                Response::new_ok(id, CompletionResponse::Array(items))
+            }
+        }
+    }
+
+    fn handle_notification(
+        &mut self,
+        connection: &Connection,
+        notification: Notification,
+    ) -> Result<(), LspError> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = params_of::<DidOpenTextDocumentParams>(connection, notification)?;
+                if let Some(params) = params
+                    && let Some(path) = text::path_of(&params.text_document.uri)
+                {
+                    self.documents.insert(path, params.text_document.text);
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = params_of::<DidChangeTextDocumentParams>(connection, notification)?;
+                if let Some(params) = params
+                    && let Some(path) = text::path_of(&params.text_document.uri)
+                    && let Some(change) = params.content_changes.into_iter().last()
+                {
+                    self.documents.insert(path, change.text);
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = params_of::<DidCloseTextDocumentParams>(connection, notification)?;
+                if let Some(params) = params
+                    && let Some(path) = text::path_of(&params.text_document.uri)
+                {
+                    self.documents.remove(&path);
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                // Saves are seen by the folder's watcher, if it has one...
+                let params = params_of::<DidSaveTextDocumentParams>(connection, notification)?;
+                if let Some(params) = params
+                    && let Some(path) = text::path_of(&params.text_document.uri)
+                    && let Some(index) = self.folder_of(&path)
+                    && self.folders[index].watcher.is_none()
+                {
+                    self.audit(connection, index)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Answers a query about the position in a Rust source, using the analysis of the
+    /// folder containing it.
+    fn query<T>(
+        &self,
+        params: &TextDocumentPositionParams,
+        query: impl FnOnce(&Analysis, &Path, &str, lsp_types::Position) -> T,
+    ) -> Option<T> {
+        let path = text::path_of(&params.text_document.uri)?;
+        let analysis = self.folders[self.folder_of(&path)?].analysis.as_ref()?;
+        let source = self.text_of(&path);
+        Some(query(analysis, &path, &source, params.position))
+    }
+
+    /// The index of the innermost folder containing the path.
+    fn folder_of(&self, path: &Path) -> Option<usize> {
+        self.folders
+            .iter()
+            .enumerate()
+            .filter(|(_, folder)| path.starts_with(&folder.root))
+            .max_by_key(|(_, folder)| folder.root.components().count())
+            .map(|(index, _)| index)
+    }
+
+    /// The editor's text of the file if it is open, else its content on disk.
+    fn text_of(&self, path: &Path) -> String {
+        self.documents
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| fs::read_to_string(path).unwrap_or_default())
+    }
+
+    /// Re-runs the audit of the folder, with the relative paths of its settings taken from
+    /// the folder, and publishes its diagnostics.
+    ///
+    /// If the audit fails, e.g. because `Lingora.toml` is part way through being edited,
+    /// the failure is logged and the previous diagnostics remain.
+    fn audit(&mut self, connection: &Connection, index: usize) -> Result<(), LspError> {
+        let core_args = self.args.core_args();
+        let folder = &mut self.folders[index];
+
+        let analysis = LingoraToml::try_from_args_in(core_args, &folder.root)
+            .map_err(LspError::from)
+            .and_then(|settings| Analysis::new(&folder.root, settings, &mut folder.cache));
+
+        let analysis = match analysis {
+            Ok(analysis) => analysis,
+            Err(error) => return log(connection, &folder.root, error),
+        };
+
+        let settings_changed = folder
+            .analysis
+            .as_ref()
+            .is_none_or(|previous| previous.settings() != analysis.settings());
+
+        if settings_changed {
+            let config_file = core_args.config_file_in(&folder.root);
+            folder.watcher = match SourceWatcher::new(analysis.settings(), config_file.as_deref()) {
+                Ok(watcher) => Some(watcher),
+                Err(error) => {
+                    log(connection, &folder.root, error.into())?;
+                    None
+                }
+            };
+        }
+
+        folder.analysis = Some(analysis);
+        self.publish(connection, index)
+    }
+
+    /// Publishes the diagnostics of the folder, clearing those of files previously
+    /// published that no longer have any.
+    fn publish(&mut self, connection: &Connection, index: usize) -> Result<(), LspError> {
+        let folder = &self.folders[index];
+        let mut diagnostics = folder
+            .analysis
+            .as_ref()
+            .map(|analysis| analysis.diagnostics(saved_text_of))
+            .unwrap_or_default();
+
+        let published = HashSet::from_iter(diagnostics.keys().cloned());
+        folder.published.difference(&published).for_each(|path| {
+            diagnostics.insert(path.clone(), Vec::new());
+        });
+
+        for (path, diagnostics) in diagnostics {
+            let Some(uri) = text::uri_of(&path) else {
+                continue;
+            };
+            let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+            send(
+                connection,
+                Notification::new(PublishDiagnostics::METHOD.into(), params),
+            )?;
+        }
+
+        self.folders[index].published = published;
+        Ok(())
+    }
+
+    /// Re-audits the folders whose sources have changed, and then been unchanged for a
+    /// poll, so that a burst of changes re-runs the audit once.
+    fn poll_watchers(&mut self, connection: &Connection) -> Result<(), LspError> {
+        for index in 0..self.folders.len() {
+            let folder = &mut self.folders[index];
+            let Some(watcher) = &folder.watcher else {
+                continue;
+            };
+
+            match watcher.try_changes() {
+                Ok(Some(_)) => folder.changes_pending = true,
+                Ok(None) if std::mem::take(&mut folder.changes_pending) => {
+                    self.audit(connection, index)?
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    folder.watcher = None;
+                    log(connection, &folder.root, error.into())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The content of the file on disk, from which the audit was run, so that the locations it
+/// reports are placed as they were found, rather than in the editor's unsaved text.
+fn saved_text_of(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_default()
+}
+
+/// The roots of the workspace folders opened in the editor, or of its root if it does not
+/// support folders, or else the current directory.
+fn roots(params: &InitializeParams) -> Vec<PathBuf> {
+    let folders = Vec::from_iter(
+        params
+            .workspace_folders
+            .iter()
+            .flatten()
+            .filter_map(|folder| text::path_of(&folder.uri)),
+    );
+    if !folders.is_empty() {
+        return folders;
+    }
+
+    #[allow(deprecated)]
+    let root = params.root_uri.as_ref().and_then(text::path_of);
+    Vec::from_iter(root.or_else(|| env::current_dir().ok()))
+}
+
+fn send(connection: &Connection, message: impl Into<Message>) -> Result<(), LspError> {
+    connection
+        .sender
+        .send(message.into())
+        .map_err(|error| std::io::Error::other(error).into())
+}
+
+/// Parses the parameters of the notification, or logs them to the editor, and ignores the
+/// notification, if they are malformed.
+fn params_of<P: DeserializeOwned>(
+    connection: &Connection,
+    notification: Notification,
+) -> Result<Option<P>, LspError> {
+    match serde_json::from_value::<P>(notification.params) {
+        Ok(params) => Ok(Some(params)),
+        Err(error) => {
+            let message = format!("invalid parameters for '{}': {error}", notification.method);
+            log_message(connection, message).map(|_| None)
+        }
+    }
+}
+
+/// Logs the failure to audit the folder at `root` to the editor.
+fn log(connection: &Connection, root: &Path, error: LspError) -> Result<(), LspError> {
+    log_message(connection, format!("{}: {error}", root.display()))
+}
+
+fn log_message(connection: &Connection, message: String) -> Result<(), LspError> {
+    let params = LogMessageParams {
+        typ: MessageType::ERROR,
+        message,
+    };
+    send(
+        connection,
+        Notification::new(LogMessage::METHOD.into(), params),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use clap::Parser;
+    use lsp_server::{RequestId, Response};
+    use lsp_types::{WorkspaceFolder, request::Initialize};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Receives messages from the server until the response to the request `id`, returning
+    /// it with the messages logged before it.
+    fn response_to(client: &Connection, id: i32) -> (Response, Vec<String>) {
+        let mut logged = Vec::new();
+        loop {
+            match client.receiver.recv().expect("message from server") {
+                Message::Response(response) if response.id == RequestId::from(id) => {
+                    return (response, logged);
+                }
+                Message::Notification(notification)
+                    if notification.method == LogMessage::METHOD =>
+                {
+                    let params = serde_json::from_value::<LogMessageParams>(notification.params)
+                        .expect("log message");
+                    logged.push(params.message);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn request(client: &Connection, id: i32, method: &str, params: serde_json::Value) {
+        let request = Request::new(RequestId::from(id), method.into(), params);
+        client.sender.send(request.into()).expect("request sent");
+    }
+
+    fn notify(client: &Connection, method: &str, params: serde_json::Value) {
+        let notification = Notification::new(method.into(), params);
+        client
+            .sender
+            .send(notification.into())
+            .expect("notification sent");
+    }
+
+    #[test]
+    fn malformed_parameters_do_not_stop_the_server() {
+        let root = TempDir::new().expect("temp dir");
+        let (server, client) = Connection::memory();
+        let args = LspArgs::parse_from(["lingora-lsp"]);
+        let running = thread::spawn(move || Server::new(args).run(&server));
+
+        let folder = WorkspaceFolder {
+            uri: text::uri_of(root.path()).expect("folder uri"),
+            name: String::from("root"),
+        };
+        let params = InitializeParams {
+            workspace_folders: Some(vec![folder]),
+            ..Default::default()
+        };
+        request(&client, 1, Initialize::METHOD, json!(params));
+        response_to(&client, 1);
+        notify(&client, "initialized", json!({}));
+
+        request(
+            &client,
+            2,
+            HoverRequest::METHOD,
+            json!({ "position": "nowhere" }),
+        );
+        let (response, _) = response_to(&client, 2);
+        assert_eq!(
+            response.error.map(|error| error.code),
+            Some(ErrorCode::InvalidParams as i32)
+        );
+
+        notify(
+            &client,
+            DidOpenTextDocument::METHOD,
+            json!({ "textDocument": 0 }),
+        );
+        request(&client, 3, "shutdown", serde_json::Value::Null);
+        let (response, logged) = response_to(&client, 3);
+        assert!(response.error.is_none());
+        assert!(
+            logged
+                .iter()
+                .any(|message| message.starts_with("invalid parameters for 'textDocument/didOpen'")),
+            "{logged:#?}"
+        );
+
+        notify(&client, Exit::METHOD, serde_json::Value::Null);
+        assert!(running.join().expect("server thread").is_ok());
+    }
+}
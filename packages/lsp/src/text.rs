@@ -0,0 +1,133 @@
+use std::{
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
+
+use lsp_types::{Position, Uri};
+use url::Url;
+
+/// Converts a one-based line and column, counted in characters as in a `SourceLocation`,
+/// to a zero-based position in the text, counted in UTF-16 code units as required by LSP.
+pub fn position(text: &str, line: usize, column: usize) -> Position {
+    let line_text = text.lines().nth(line.saturating_sub(1)).unwrap_or_default();
+    let character = line_text
+        .chars()
+        .take(column.saturating_sub(1))
+        .map(char::len_utf16)
+        .sum::<usize>();
+
+    Position::new(line.saturating_sub(1) as u32, character as u32)
+}
+
+/// Converts a zero-based LSP position in the text to a one-based line and column, counted
+/// in characters as in a `SourceLocation`.
+pub fn line_column(text: &str, position: Position) -> (usize, usize) {
+    let line_text = text.lines().nth(position.line as usize).unwrap_or_default();
+
+    let mut units = 0;
+    let column = line_text
+        .chars()
+        .take_while(|c| {
+            let within = units < position.character as usize;
+            units += c.len_utf16();
+            within
+        })
+        .count();
+
+    (position.line as usize + 1, column + 1)
+}
+
+/// Returns the one-based column just past the token starting at the given line and
+/// column, i.e. a string literal, or a run of identifier characters, or otherwise a
+/// single character; used to give a diagnostic reported at a location some extent.
+pub fn token_end(text: &str, line: usize, column: usize) -> usize {
+    let line_text = text.lines().nth(line.saturating_sub(1)).unwrap_or_default();
+    let mut chars = line_text.chars().skip(column.saturating_sub(1)).peekable();
+    let is_word = |c: &char| c.is_alphanumeric() || matches!(c, '-' | '_');
+
+    let length = match chars.next() {
+        Some('"') => {
+            let mut escaped = false;
+            let length = chars
+                .position(|c| {
+                    let closes = c == '"' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    closes
+                })
+                .map_or(0, |i| i + 1);
+            length + 1
+        }
+        Some(c) if is_word(&c) => chars.take_while(is_word).count() + 1,
+        _ => 1,
+    };
+
+    column + length
+}
+
+/// The path of a `file:` URI, if it is one.
+pub fn path_of(uri: &Uri) -> Option<PathBuf> {
+    Url::parse(uri.as_str()).ok()?.to_file_path().ok()
+}
+
+/// The `file:` URI of an absolute path.
+pub fn uri_of(path: &Path) -> Option<Uri> {
+    let url = Url::from_file_path(path).ok()?;
+    Uri::from_str(url.as_str()).ok()
+}
+
+/// Resolves a path reported by the audit, which is relative to the workspace folder
+/// unless the settings gave it absolutely, to an absolute path without `.` components.
+pub fn resolve(root: &Path, path: &Path) -> PathBuf {
+    root.join(path)
+        .components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn positions_will_count_utf16_code_units() {
+        let text = "first\nlet 𝔸 = t!(\"greeting\");\n";
+
+        assert_eq!(position(text, 2, 9), Position::new(1, 9));
+        assert_eq!(line_column(text, Position::new(1, 9)), (2, 9));
+        assert_eq!(position(text, 1, 1), Position::new(0, 0));
+        assert_eq!(line_column(text, Position::new(0, 0)), (1, 1));
+    }
+
+    #[test]
+    fn tokens_will_extend_over_literals_and_identifiers() {
+        let text = "greeting-title = Hello\nt!(\"say \\\"hi\\\"\", name: n)\n= oops";
+
+        assert_eq!(token_end(text, 1, 1), 15);
+        assert_eq!(token_end(text, 2, 4), 16);
+        assert_eq!(token_end(text, 3, 1), 2);
+        assert_eq!(token_end(text, 4, 1), 2);
+    }
+
+    #[test]
+    fn paths_will_be_resolved_against_the_root() {
+        let root = Path::new("/workspace");
+
+        assert_eq!(
+            resolve(root, Path::new("./i18n/en-GB.ftl")),
+            Path::new("/workspace/i18n/en-GB.ftl")
+        );
+        assert_eq!(
+            resolve(root, Path::new("/elsewhere/en-GB.ftl")),
+            Path::new("/elsewhere/en-GB.ftl")
+        );
+
+        let uri = uri_of(Path::new("/workspace/i18n/en GB.ftl")).expect("valid uri");
+        assert_eq!(uri.as_str(), "file:///workspace/i18n/en%20GB.ftl");
+        assert_eq!(
+            path_of(&uri),
+            Some(PathBuf::from("/workspace/i18n/en GB.ftl"))
+        );
+    }
+}
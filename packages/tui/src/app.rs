@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 use crossterm::event;
 use lingora_core::prelude::*;
@@ -15,12 +15,17 @@ use crate::{
     user_preferences::UserPreferences,
 };
 
+/// How long to wait for a terminal event before checking whether the sources have changed.
+const POLL_TIMEOUT: Duration = Duration::from_millis(250);
+
 /// The main application state and driver for the interactive terminal user interface.
 ///
 /// `App` owns:
 /// - The visual theme (`LingoraTheme`)
 /// - The `AuditResult` (shared across widgets/views)
 /// - The current application view state (`AppViewState`)
+/// - The settings, and optionally the arguments and watcher, used to re-run the audit
+/// - The `ParseCache` kept between re-runs, so that only changed files are re-parsed
 ///
 /// Responsibilities:
 /// - Initialize from configuration and audit result
@@ -28,7 +33,13 @@ use crate::{
 /// - Delegate rendering to `AppView` (stateful widget)
 /// - Forward keyboard/mouse events to the view state
 /// - Manage cursor visibility and position
+/// - Re-run the audit on request (F5), or when the watched sources change
 pub struct App {
+    settings: LingoraToml,
+    core_args: Option<CoreArgs>,
+    watcher: Option<SourceWatcher>,
+    changes_pending: bool,
+    cache: ParseCache,
     audit_result: Rc<AuditResult>,
     state: AppViewState,
 }
@@ -45,11 +56,31 @@ impl App {
         let state = AppViewState::new(&settings, theme, audit_result.clone());
 
         Self {
+            settings,
+            core_args: None,
+            watcher: None,
+            changes_pending: false,
+            cache: ParseCache::default(),
             audit_result,
             state,
         }
     }
 
+    /// Watches the sources, and the configuration file, from which the settings were read
+    /// and returns `self` (builder-style).
+    ///
+    /// The audit is then re-run, re-reading the settings from the arguments, whenever they
+    /// change, rather than only when refreshed by the user.
+    ///
+    /// # Errors
+    /// Returns `TuiError::Lingora` if the sources cannot be watched.
+    pub fn watch(mut self, core_args: &CoreArgs) -> Result<Self, TuiError> {
+        let config_file = core_args.config_file();
+        self.watcher = Some(SourceWatcher::new(&self.settings, config_file.as_deref())?);
+        self.core_args = Some(core_args.clone());
+        Ok(self)
+    }
+
     /// Replaces the base theme and returns `self` (builder-style).
     pub fn set_theme(mut self, theme: ThemeName) -> Self {
         self.state.set_theme(theme);
@@ -60,21 +91,87 @@ impl App {
     ///
     /// Loop steps:
     /// 1. Draw current frame using `AppView` widget
-    /// 2. Read next crossterm event
+    /// 2. Wait briefly for the next crossterm event
     /// 3. Handle event (keyboard, mouse, resize) via `AppViewState`
-    /// 4. Repeat until `state.is_running()` returns `false` (usually on 'q' or Ctrl+C)
+    /// 4. Re-run the audit if requested, or if the watched sources have changed and then
+    ///    settled
+    /// 5. Repeat until `state.is_running()` returns `false` (usually on 'q' or Ctrl+C)
     ///
     /// # Errors
-    /// Propagates terminal I/O or event reading failures as `TuiError::Io`.
+    /// Propagates terminal I/O or event reading failures as `TuiError::Io`, and failures
+    /// of the watcher as `TuiError::Lingora`. Failures in re-running the audit are shown
+    /// in the footer instead.
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), TuiError> {
         while self.state.is_running() {
             terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events()?;
+
+            if event::poll(POLL_TIMEOUT)? {
+                self.handle_events()?;
+            }
+
+            if self.state.take_refresh_request() | self.sources_changed()? {
+                self.refresh();
+            }
         }
 
         Ok(())
     }
 
+    /// Re-runs the audit, re-reading the settings if they came from the arguments, and
+    /// replaces the audit result in the view.
+    pub fn refresh(&mut self) {
+        match self.rerun() {
+            Ok((settings, audit_result)) => {
+                self.audit_result = Rc::new(audit_result);
+                self.state.refresh(&settings, self.audit_result.clone());
+
+                if settings != self.settings {
+                    self.settings = settings;
+                    if let Err(error) = self.rewatch() {
+                        self.state.set_refresh_error(error.to_string());
+                    }
+                }
+            }
+            Err(error) => self.state.set_refresh_error(error.to_string()),
+        }
+    }
+
+    fn rerun(&mut self) -> Result<(LingoraToml, AuditResult), TuiError> {
+        let settings = match &self.core_args {
+            Some(core_args) => LingoraToml::try_from(core_args)?,
+            None => self.settings.clone(),
+        };
+        let audit_result = AuditEngine::try_from(&settings)?.run_with_cache(&mut self.cache)?;
+
+        Ok((settings, audit_result))
+    }
+
+    /// Watches the sources of the current settings, which may differ from those watched.
+    fn rewatch(&mut self) -> Result<(), TuiError> {
+        if let Some(core_args) = &self.core_args {
+            let config_file = core_args.config_file();
+            self.watcher = Some(SourceWatcher::new(&self.settings, config_file.as_deref())?);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` once the watched sources have changed, and then been unchanged for
+    /// a poll, so that a burst of changes re-runs the audit once.
+    fn sources_changed(&mut self) -> Result<bool, TuiError> {
+        let Some(watcher) = &self.watcher else {
+            return Ok(false);
+        };
+
+        match watcher.try_changes()? {
+            Some(_) => {
+                self.changes_pending = true;
+                Ok(false)
+            }
+            None => Ok(std::mem::take(&mut self.changes_pending)),
+        }
+    }
+
     fn draw(&mut self, frame: &mut Frame) {
         let mut view = AppView::new(&self.audit_result);
 
@@ -111,6 +208,8 @@ impl TryFrom<&TuiArgs> for App {
             Some(theme) => theme,
             None => UserPreferences::load().theme(),
         };
-        Self::try_from(settings).map(|app| app.set_theme(theme))
+        Self::try_from(settings)?
+            .set_theme(theme)
+            .watch(value.core_args())
     }
 }
@@ -11,6 +11,7 @@ pub struct IdentifierListState {
     focus_flag: FocusFlag,
     list_state: ListState,
     selected: Option<QualifiedIdentifier>,
+    reselect: bool,
     area: Rect,
 }
 
@@ -19,7 +20,22 @@ impl IdentifierListState {
         self.selected.as_ref()
     }
 
+    /// Reselects the selected identifier, by name rather than position, when next rendered,
+    /// as the identifiers may have changed.
+    pub fn refresh(&mut self) {
+        self.reselect = true;
+    }
+
     fn update_selected(&mut self, items: &[QualifiedIdentifier]) {
+        if std::mem::take(&mut self.reselect)
+            && let Some(index) = self
+                .selected
+                .as_ref()
+                .and_then(|selected| items.iter().position(|item| item == selected))
+        {
+            self.list_state.select(Some(index));
+        }
+
         self.selected = self
             .list_state
             .selected()
@@ -19,6 +19,11 @@ pub struct IdentifiersState {
 }
 
 impl IdentifiersState {
+    #[inline]
+    pub fn refresh(&mut self) {
+        self.list_state.refresh();
+    }
+
     #[inline(always)]
     pub fn filter(&self) -> &str {
         self.filter_state.text()
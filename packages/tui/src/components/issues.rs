@@ -1,5 +1,5 @@
 use crossterm::event::{Event, KeyCode, KeyEvent};
-use lingora_core::prelude::AuditIssue;
+use lingora_core::prelude::{AuditIssue, Severity};
 use rat_event::{HandleEvent, Outcome, Regular};
 use rat_focus::{FocusFlag, HasFocus};
 use ratatui::{prelude::*, widgets::*};
@@ -76,7 +76,32 @@ impl StatefulWidget for &Issues<'_> {
     {
         state.area = area;
 
-        let list = List::new(self.entries.iter().map(|i| i.to_string()))
+        let items = self.entries.iter().map(|issue| {
+            let style = match issue.severity() {
+                Severity::Error => self.theme.error(),
+                Severity::Warning => self.theme.warning(),
+                Severity::Info => self.theme.accent(),
+                Severity::Off => self.theme.muted(),
+            };
+
+            let changes = issue
+                .signature_diff()
+                .into_iter()
+                .flat_map(|diff| diff.changes())
+                .map(|change| Line::styled(format!("  - {change}"), self.theme.muted()));
+            let help = issue
+                .help()
+                .map(|help| Line::styled(format!("  help: {help}"), self.theme.muted()));
+            let lines = Vec::from_iter(
+                std::iter::once(Line::from(issue.to_string()))
+                    .chain(changes)
+                    .chain(help),
+            );
+
+            ListItem::new(lines).style(style)
+        });
+
+        let list = List::new(items)
             .block(self.theme.focus_block(&state.focus_flag))
             .highlight_style(self.theme.selection())
             .highlight_symbol("» ")
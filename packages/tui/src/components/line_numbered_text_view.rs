@@ -28,6 +28,11 @@ impl LineNumberedTextViewState {
         }
     }
 
+    /// Replaces the content, keeping the scroll position.
+    pub fn set_content(&mut self, content: String) {
+        self.content = content;
+    }
+
     fn handle_key_event(&mut self, event: &KeyEvent) -> Outcome {
         match &event.code {
             KeyCode::Up => {
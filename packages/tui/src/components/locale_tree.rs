@@ -9,6 +9,7 @@ use tui_tree_widget::{Tree, TreeItem, TreeState};
 use crate::{
     projections::{
         FilteredLocalesHierarchy, HasSelectionPair, LocaleNode, LocaleNodeId, LocaleNodeKind,
+        LocalesHierarchy,
     },
     theme::LingoraTheme,
 };
@@ -48,6 +49,49 @@ impl LocaleTreeState {
         }
     }
 
+    /// Carries the reference and target selection, and the open, selected and scrolled
+    /// nodes, over from the `previous` hierarchy to the `current` one, where the nodes still
+    /// exist. Nodes new to the `current` hierarchy are opened, as they are initially.
+    pub fn refresh(
+        &mut self,
+        previous: &LocalesHierarchy,
+        current: &LocalesHierarchy,
+        default_reference: Option<LocaleNodeId>,
+    ) {
+        let carry = |id: &LocaleNodeId| current.node_id_from(previous, id);
+        let carry_path = |path: &[LocaleNodeId]| path.iter().map(carry).collect::<Option<Vec<_>>>();
+
+        let opened = self.tree_state.opened().clone();
+        let selected = carry_path(self.tree_state.selected()).unwrap_or_default();
+
+        // The tree state is kept, rather than replaced, so that its scroll offset is kept.
+        self.tree_state.close_all();
+
+        current
+            .nodes()
+            .keys()
+            .filter(|id| !previous.nodes().keys().any(|p| carry(p) == Some(**id)))
+            .for_each(|id| {
+                self.tree_state.open(vec![*id]);
+            });
+
+        opened
+            .iter()
+            .filter_map(|path| carry_path(path))
+            .for_each(|path| {
+                self.tree_state.open(path);
+            });
+
+        self.tree_state.select(selected);
+
+        self.reference = self
+            .reference
+            .as_ref()
+            .and_then(carry)
+            .or(default_reference);
+        self.target = self.target.as_ref().and_then(carry);
+    }
+
     fn handle_key_event(&mut self, event: &KeyEvent) -> Outcome {
         match &event.code {
             KeyCode::Up => {
@@ -29,6 +29,17 @@ impl LocalesState {
         }
     }
 
+    /// Carries the selection over to a rebuilt hierarchy, keeping the filter.
+    pub fn refresh(
+        &mut self,
+        previous: &LocalesHierarchy,
+        current: &LocalesHierarchy,
+        default_reference: Option<LocaleNodeId>,
+    ) {
+        self.tree_state
+            .refresh(previous, current, default_reference);
+    }
+
     pub fn filter(&self) -> &str {
         self.filter_state.text()
     }
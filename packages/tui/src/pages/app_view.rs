@@ -23,6 +23,7 @@ use crate::{
 enum RunState {
     #[default]
     Running,
+    Refresh,
     Quit,
 }
 
@@ -57,6 +58,7 @@ pub struct AppViewState {
     translations_state: TranslationsState,
     dioxus_i18n_config_state: DioxusI18nConfigState,
     settings_state: SettingsState,
+    refresh_error: Option<String>,
 }
 
 impl AppViewState {
@@ -71,11 +73,38 @@ impl AppViewState {
                 audit_result.workspace(),
             ),
             settings_state: SettingsState::new(settings),
+            refresh_error: None,
         }
     }
 
     pub fn is_running(&self) -> bool {
-        matches!(self.run_state, RunState::Running)
+        !matches!(self.run_state, RunState::Quit)
+    }
+
+    /// Returns `true`, once, if the user has asked for the audit to be re-run.
+    pub fn take_refresh_request(&mut self) -> bool {
+        let requested = matches!(self.run_state, RunState::Refresh);
+        if requested {
+            self.run_state = RunState::Running;
+        }
+        requested
+    }
+
+    /// Replaces the settings and audit result, after the audit is re-run, keeping the
+    /// current page, selections, filters and scroll positions where they still apply.
+    pub fn refresh(&mut self, settings: &LingoraToml, audit_result: Rc<AuditResult>) {
+        self.theme.refresh(audit_result.workspace());
+        self.dioxus_i18n_config_state
+            .refresh(settings, audit_result.workspace());
+        self.settings_state.refresh(settings);
+        self.translations_state.refresh(audit_result);
+        self.refresh_error = None;
+    }
+
+    /// Records an error in re-running the audit, e.g. an invalid `Lingora.toml` part way
+    /// through being edited; the previous audit result continues to be shown.
+    pub fn set_refresh_error(&mut self, error: String) {
+        self.refresh_error = Some(error);
     }
 
     fn handle_key_event(&mut self, event: &KeyEvent) -> Outcome {
@@ -92,6 +121,7 @@ impl AppViewState {
                 Outcome::Changed
             }
             KeyCode::F(1) => self.set_page(Page::Help),
+            KeyCode::F(5) => self.refresh_request(),
             _ => Outcome::Continue,
         }
     }
@@ -102,6 +132,12 @@ impl AppViewState {
         Outcome::Changed
     }
 
+    #[inline]
+    fn refresh_request(&mut self) -> Outcome {
+        self.run_state = RunState::Refresh;
+        Outcome::Changed
+    }
+
     #[inline]
     fn set_page(&mut self, page: Page) -> Outcome {
         self.page = page;
@@ -230,8 +266,22 @@ impl<'a> StatefulWidget for &mut AppView<'a> {
         ])
         .centered();
 
-        let footer_left =
-            Line::from(vec![state.theme.accent_span("F1"), Span::from(" - Help")]).left_aligned();
+        let footer_left = Line::from(vec![
+            state.theme.accent_span("F1"),
+            Span::from(" - Help  "),
+            state.theme.accent_span("F5"),
+            Span::from(" - Refresh"),
+        ])
+        .left_aligned();
+
+        let footer_centre = Line::from(
+            state
+                .refresh_error
+                .as_deref()
+                .map(|error| Span::styled(format!(" {error} "), state.theme.error()))
+                .unwrap_or_default(),
+        )
+        .centered();
 
         let reference =
             node_span(reference.and_then(|id| state.translations_state.locale_node(id)));
@@ -249,6 +299,7 @@ impl<'a> StatefulWidget for &mut AppView<'a> {
         Block::new()
             .title(title)
             .title_bottom(footer_left)
+            .title_bottom(footer_centre)
             .title_bottom(footer_right)
             .style(state.theme.default_style())
             .render(area, buf);
@@ -17,11 +17,7 @@ pub struct DioxusI18nConfigState {
 
 impl DioxusI18nConfigState {
     pub fn new(settings: &LingoraToml, workspace: &Workspace) -> Self {
-        let mut cursor = std::io::Cursor::new(Vec::new());
-        let renderer = DioxusI18nConfigRenderer::new(settings, workspace, None);
-        let _ = renderer.render(&mut cursor);
-
-        let content = String::from_utf8_lossy(&cursor.into_inner()).to_string();
+        let content = Self::content(settings, workspace);
         let text_view_state = LineNumberedTextViewState::new(content);
 
         Self {
@@ -29,6 +25,19 @@ impl DioxusI18nConfigState {
             area: Rect::default(),
         }
     }
+
+    pub fn refresh(&mut self, settings: &LingoraToml, workspace: &Workspace) {
+        self.text_view_state
+            .set_content(Self::content(settings, workspace));
+    }
+
+    fn content(settings: &LingoraToml, workspace: &Workspace) -> String {
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        let renderer = DioxusI18nConfigRenderer::new(settings, workspace, None);
+        let _ = renderer.render(&mut cursor);
+
+        String::from_utf8_lossy(&cursor.into_inner()).to_string()
+    }
 }
 
 impl HasFocus for DioxusI18nConfigState {
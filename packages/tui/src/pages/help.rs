@@ -43,6 +43,10 @@ impl Widget for Help<'_> {
                 Cell::from(self.theme.accent_span("F1")),
                 Cell::from(Span::from("This page")),
             ]),
+            Row::new(vec![
+                Cell::from(self.theme.accent_span("F5")),
+                Cell::from(Span::from("Refresh the audit")),
+            ]),
             Row::new(vec![
                 Cell::from(self.theme.accent_span("PgUp/PgDn")),
                 Cell::from(Span::from("Page up/down")),
@@ -25,6 +25,10 @@ impl SettingsState {
             area: Rect::default(),
         }
     }
+
+    pub fn refresh(&mut self, settings: &LingoraToml) {
+        self.text_view_state.set_content(settings.to_string());
+    }
 }
 
 impl HasFocus for SettingsState {
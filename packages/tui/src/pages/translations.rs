@@ -60,6 +60,28 @@ impl TranslationsState {
         }
     }
 
+    /// Replaces the audit result, e.g. after the audit is re-run, keeping the reference and
+    /// target selection, the filters and the scroll positions where they still apply.
+    pub fn refresh(&mut self, audit_result: Rc<AuditResult>) {
+        let canonical_locale = audit_result.workspace().canonical_locale();
+
+        let locales_hierarchy = LocalesHierarchy::from(&*audit_result);
+        let canonical_node_id = locales_hierarchy
+            .node_id_for_locale(canonical_locale)
+            .copied();
+
+        self.locales_state.refresh(
+            self.comparison.locales_hierarchy(),
+            &locales_hierarchy,
+            canonical_node_id,
+        );
+        self.identifiers_state.refresh();
+
+        let reference_node_id = self.reference().copied();
+        self.comparison =
+            Comparison::from_reference(reference_node_id, audit_result, locales_hierarchy);
+    }
+
     pub fn rebuild_focus(&mut self) {
         let mut builder = FocusBuilder::new(self.focus.take());
         self.build(&mut builder);
@@ -78,6 +78,19 @@ impl LocalesHierarchy {
             LocaleNodeKind::Locale { locale } => (locale == required_locale).then_some(id),
         })
     }
+
+    /// The id of the node, if any, of the same kind as the node in the `previous` hierarchy,
+    /// e.g. when the hierarchy is rebuilt after the audit is re-run.
+    pub fn node_id_from(
+        &self,
+        previous: &LocalesHierarchy,
+        node_id: &LocaleNodeId,
+    ) -> Option<LocaleNodeId> {
+        let kind = previous.node(node_id)?.kind();
+        self.nodes
+            .iter()
+            .find_map(|(id, node)| (node.kind() == kind).then_some(*id))
+    }
 }
 
 impl From<&AuditResult> for LocalesHierarchy {
@@ -32,6 +32,12 @@ impl LingoraTheme {
         }
     }
 
+    pub fn refresh(&mut self, workspace: &Workspace) {
+        self.canonical = workspace.canonical_locale().clone();
+        self.primaries = Vec::from_iter(workspace.primary_locales().cloned());
+        self.orphans = Vec::from_iter(workspace.orphan_locales().cloned());
+    }
+
     #[inline]
     pub fn base(&self) -> ThemeName {
         self.base.name